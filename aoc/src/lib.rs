@@ -0,0 +1,272 @@
+use std::ffi::OsString;
+use std::io::{self, IsTerminal, Read};
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+use std::{env, error, fmt, fs, process};
+
+mod parse;
+mod report;
+
+pub use parse::{blocks, lines, rows, Line, Lines, ParseError};
+pub use report::{take_warnings, warn, Report};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::One => f.pad("a"),
+            Self::Two => f.pad("b"),
+        }
+    }
+}
+
+// one part of one day: parse the puzzle input once, then solve from the parsed form;
+// a malformed input is a ParseError, never a panic
+pub trait Solution {
+    const DAY: u8;
+    const PART: Part;
+    type Input;
+    type Answer: fmt::Display;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+    fn solve(input: &Self::Input) -> Self::Answer;
+}
+
+// an answer worked out with checked arithmetic or a bounded search: the value,
+// or why there is none, so a wrong number is never printed
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Checked<T> {
+    Value(T),
+    TooLarge,
+    NotFound,
+}
+
+impl<T> From<Option<T>> for Checked<T> {
+    // None is an overflow
+    fn from(value: Option<T>) -> Self {
+        value.map_or(Checked::TooLarge, Checked::Value)
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Checked<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Checked::Value(v) => v.fmt(f),
+            Checked::TooLarge => f.pad("too large"),
+            Checked::NotFound => f.pad("not found"),
+        }
+    }
+}
+
+// S::parse with the day filled in on any error
+pub fn parse<S: Solution>(input: &str) -> Result<S::Input, ParseError> {
+    S::parse(input).map_err(|e| e.on_day(S::DAY))
+}
+
+pub fn solve<S: Solution>(input: &str) -> Result<S::Answer, ParseError> {
+    parse::<S>(input).map(|input| S::solve(&input))
+}
+
+// environment variable naming the input file when no path argument is given
+pub const INPUT_ENV: &str = "AOC_INPUT";
+// flag switching a day binary to one JSON Report line on stdout
+pub const JSON_FLAG: &str = "--json";
+// flag asking a day binary for its report on stdout instead of the answer
+pub const REPORT_FLAG: &str = "--report";
+// flag asking for the report as CSV, where a day has one
+pub const CSV_FLAG: &str = "--csv";
+
+// command line arguments other than flags
+fn args() -> impl Iterator<Item = OsString> {
+    env::args_os()
+        .skip(1)
+        .filter(|a| !a.to_string_lossy().starts_with("--"))
+}
+
+pub fn json_output() -> bool {
+    env::args().skip(1).any(|a| a == JSON_FLAG)
+}
+
+pub fn report_requested() -> bool {
+    env::args().skip(1).any(|a| a == REPORT_FLAG)
+}
+
+pub fn csv_requested() -> bool {
+    env::args().skip(1).any(|a| a == CSV_FLAG)
+}
+
+// the value given to a flag as "--flag=value"
+pub fn flag_value(flag: &str) -> Option<String> {
+    env::args()
+        .skip(1)
+        .find_map(|a| Some(a.strip_prefix(flag)?.strip_prefix('=')?.to_string()))
+}
+
+#[derive(Debug)]
+pub enum InputError {
+    Missing,
+    Empty(String),
+    Read(String, io::Error),
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Missing => write!(
+                f,
+                "no puzzle input: pass a file path, set {} or pipe the input on stdin",
+                INPUT_ENV
+            ),
+            Self::Empty(src) => write!(f, "puzzle input from {} is empty", src),
+            Self::Read(src, e) => write!(f, "cannot read puzzle input from {}: {}", src, e),
+        }
+    }
+}
+
+impl error::Error for InputError {}
+
+fn read_file(path: PathBuf) -> Result<(String, String), InputError> {
+    let src = path.display().to_string();
+    fs::read_to_string(&path)
+        .map(|s| (src.clone(), s))
+        .map_err(|e| InputError::Read(src, e))
+}
+
+// first argument, then $AOC_INPUT, then stdin unless it is a terminal
+pub fn read_input() -> Result<String, InputError> {
+    let (src, input) = if let Some(path) = args().next() {
+        read_file(path.into())?
+    } else if let Some(path) = env::var_os(INPUT_ENV) {
+        read_file(path.into())?
+    } else if !io::stdin().is_terminal() {
+        let mut s = String::new();
+        io::stdin()
+            .read_to_string(&mut s)
+            .map_err(|e| InputError::Read("stdin".to_string(), e))?;
+        ("stdin".to_string(), s)
+    } else {
+        return Err(InputError::Missing);
+    };
+    if input.trim().is_empty() {
+        return Err(InputError::Empty(src));
+    }
+    Ok(input)
+}
+
+// the error line every day binary ends on
+pub fn exit(e: impl fmt::Display) -> ! {
+    eprintln!("error: {}", e);
+    process::exit(1)
+}
+
+pub fn input() -> String {
+    read_input().unwrap_or_else(|e| exit(e))
+}
+
+// the input of a day binary; with --json a missing input is a failed Report
+// line for each part
+fn input_for(parts: &[(u8, Part)]) -> String {
+    read_input().unwrap_or_else(|e| {
+        if !json_output() {
+            exit(e)
+        }
+        for &(day, part) in parts {
+            let report = Report {
+                day,
+                part,
+                answer: Err(e.to_string()),
+                elapsed: Duration::ZERO,
+                warnings: Vec::new(),
+            };
+            println!("{}", report.to_json());
+        }
+        process::exit(1)
+    })
+}
+
+// only S::solve is timed; show runs after it, for anything printed alongside
+fn report<S: Solution>(input: &str, show: impl FnOnce(&S::Input, &S::Answer)) -> Report {
+    take_warnings();
+    let (answer, elapsed) = match parse::<S>(input) {
+        Ok(input) => {
+            let start = Instant::now();
+            let answer = S::solve(&input);
+            let elapsed = start.elapsed();
+            show(&input, &answer);
+            (Ok(answer.to_string()), elapsed)
+        }
+        Err(e) => (Err(e.to_string()), Duration::ZERO),
+    };
+    Report {
+        day: S::DAY,
+        part: S::PART,
+        answer,
+        elapsed,
+        warnings: take_warnings(),
+    }
+}
+
+// the answer on stdout, or with --json a Report line; warnings and errors on stderr
+fn print(report: &Report) {
+    if json_output() {
+        println!("{}", report.to_json());
+        return;
+    }
+    for w in &report.warnings {
+        eprintln!("warning: {}", w);
+    }
+    match &report.answer {
+        Ok(answer) => println!("{}", answer),
+        Err(e) => eprintln!("error: {}", e),
+    }
+}
+
+// body of a day binary: read the input, solve it and print only the answer on
+// stdout, or with --json a Report line; show gets the parsed input and the
+// answer, and whatever it prints goes to stderr
+pub fn run_with<S: Solution>(show: impl FnOnce(&S::Input, &S::Answer)) {
+    let report = report::<S>(&input_for(&[(S::DAY, S::PART)]), show);
+    print(&report);
+    if report.answer.is_err() {
+        process::exit(1);
+    }
+}
+
+// body of a binary holding both parts of a day: one answer line per part
+pub fn run_both<A: Solution, B: Solution>() {
+    let input = input_for(&[(A::DAY, A::PART), (B::DAY, B::PART)]);
+    let reports = [
+        report::<A>(&input, |_, _| ()),
+        report::<B>(&input, |_, _| ()),
+    ];
+    reports.iter().for_each(print);
+    if reports.iter().any(|r| r.answer.is_err()) {
+        process::exit(1);
+    }
+}
+
+pub fn run<S: Solution>() {
+    run_with::<S>(|_, _| ())
+}
+
+// body of a day binary with a report: with --report, the report of the parsed
+// input on stdout, otherwise the answer as run prints it
+pub fn run_or_report<S: Solution>(report: impl FnOnce(&S::Input) -> String) {
+    if !report_requested() {
+        return run::<S>();
+    }
+    print_report::<S>(report);
+}
+
+// the report of the parsed input on stdout, or the error line if it does not parse
+pub fn print_report<S: Solution>(report: impl FnOnce(&S::Input) -> String) {
+    match parse::<S>(&input()) {
+        Ok(input) => print!("{}", report(&input)),
+        Err(e) => exit(e),
+    }
+}
@@ -0,0 +1,295 @@
+use std::str::FromStr;
+use std::{error, fmt};
+
+// where an input stopped making sense; lines and columns count from 1
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    // 0 until the error has passed through `aoc::parse`
+    pub day: u8,
+    pub line: usize,
+    pub column: usize,
+    pub expected: String,
+    pub found: String,
+}
+
+impl ParseError {
+    pub fn on_day(self, day: u8) -> Self {
+        ParseError { day, ..self }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.day > 0 {
+            write!(f, "day {:02}, ", self.day)?;
+        }
+        write!(
+            f,
+            "line {}, column {}: expected {}, found {}",
+            self.line, self.column, self.expected, self.found
+        )
+    }
+}
+
+impl error::Error for ParseError {}
+
+fn quoted(s: &str) -> String {
+    match s.chars().count() {
+        0 => "end of line".to_string(),
+        1 => format!("{:?}", s.chars().next().unwrap()),
+        _ => format!("{:?}", s),
+    }
+}
+
+// a piece of one input line that remembers where it came from,
+// so anything parsed out of it can point back at the input
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Line<'a> {
+    number: usize,
+    column: usize,
+    text: &'a str,
+}
+
+impl<'a> Line<'a> {
+    pub fn new(number: usize, text: &'a str) -> Self {
+        Line {
+            number,
+            column: 1,
+            text,
+        }
+    }
+    pub fn as_str(&self) -> &'a str {
+        self.text
+    }
+    pub fn number(&self) -> usize {
+        self.number
+    }
+    pub fn is_empty(&self) -> bool {
+        self.text.is_empty()
+    }
+
+    // a slice of this line's text, e.g. what a nom parser left over, as a Line
+    pub fn piece(&self, piece: &'a str) -> Line<'a> {
+        let offset = piece.as_ptr() as usize - self.text.as_ptr() as usize;
+        Line {
+            number: self.number,
+            column: self.column + self.text[..offset].chars().count(),
+            text: piece,
+        }
+    }
+    fn error_at(&self, offset: usize, found: String, expected: impl Into<String>) -> ParseError {
+        ParseError {
+            day: 0,
+            line: self.number,
+            column: self.column + self.text[..offset].chars().count(),
+            expected: expected.into(),
+            found,
+        }
+    }
+    // an error pointing at the start of this piece, quoting its first word
+    pub fn error(&self, expected: impl Into<String>) -> ParseError {
+        let word = self.text.split_ascii_whitespace().next().unwrap_or("");
+        self.error_at(0, quoted(word), expected)
+    }
+    // an error pointing just past the end of this piece
+    pub fn error_at_end(&self, expected: impl Into<String>) -> ParseError {
+        self.error_at(self.text.len(), quoted(""), expected)
+    }
+
+    pub fn trim(&self) -> Line<'a> {
+        self.piece(self.text.trim())
+    }
+    pub fn strip_prefix(&self, prefix: &str) -> Result<Line<'a>, ParseError> {
+        match self.text.strip_prefix(prefix) {
+            Some(rest) => Ok(self.piece(rest)),
+            None => Err(self.error(format!("{:?}", prefix))),
+        }
+    }
+    pub fn strip_suffix(&self, suffix: &str) -> Result<Line<'a>, ParseError> {
+        match self.text.strip_suffix(suffix) {
+            Some(rest) => Ok(self.piece(rest)),
+            None => {
+                let at = self.text.trim_end().len();
+                let found = quoted(&self.text[at.saturating_sub(1)..at]);
+                Err(self.error_at(at, found, format!("{:?} at the end", suffix)))
+            }
+        }
+    }
+    pub fn split_once(&self, sep: &str) -> Result<(Line<'a>, Line<'a>), ParseError> {
+        match self.text.split_once(sep) {
+            Some((a, b)) => Ok((self.piece(a), self.piece(b))),
+            None => Err(self.error_at_end(format!("{:?}", sep))),
+        }
+    }
+    pub fn split(self, sep: &'a str) -> impl Iterator<Item = Line<'a>> {
+        self.text.split(sep).map(move |s| self.piece(s))
+    }
+    pub fn words(self) -> impl Iterator<Item = Line<'a>> {
+        self.text
+            .split_ascii_whitespace()
+            .map(move |s| self.piece(s))
+    }
+
+    // the whole piece, ignoring surrounding whitespace, as one value
+    pub fn parse<T: FromStr>(&self, expected: &str) -> Result<T, ParseError> {
+        let t = self.trim();
+        t.text.parse().map_err(|_| match t.text.is_empty() {
+            true => t.error_at_end(expected),
+            false => t.error_at(0, quoted(t.text), expected),
+        })
+    }
+    // whitespace separated values, e.g. "41 48 83"
+    pub fn values<T: FromStr>(&self, expected: &str) -> Result<Vec<T>, ParseError> {
+        self.words().map(|w| w.parse(expected)).collect()
+    }
+    // one value per character
+    pub fn cells<T>(
+        &self,
+        expected: &str,
+        mut cell: impl FnMut(char) -> Option<T>,
+    ) -> Result<Vec<T>, ParseError> {
+        self.text
+            .char_indices()
+            .map(|(i, c)| cell(c).ok_or_else(|| self.error_at(i, quoted(&c.to_string()), expected)))
+            .collect()
+    }
+}
+
+fn lines_end(line: usize, expected: &str) -> ParseError {
+    ParseError {
+        day: 0,
+        line,
+        column: 1,
+        expected: expected.to_string(),
+        found: "end of input".to_string(),
+    }
+}
+
+// numbered lines with CRLF endings and trailing blank lines dropped
+pub struct Lines<'a> {
+    lines: std::iter::Enumerate<std::str::Lines<'a>>,
+    next: usize,
+}
+
+impl<'a> Lines<'a> {
+    // the next line, which the input must have
+    pub fn expect(&mut self, expected: &str) -> Result<Line<'a>, ParseError> {
+        let line = self.next;
+        self.next().ok_or_else(|| lines_end(line, expected))
+    }
+    // an error for something missing from the whole input, pointing past the
+    // lines read so far
+    pub fn error_at_end(&self, expected: &str) -> ParseError {
+        lines_end(self.next, expected)
+    }
+}
+
+impl<'a> Iterator for Lines<'a> {
+    type Item = Line<'a>;
+    fn next(&mut self) -> Option<Line<'a>> {
+        let (i, l) = self.lines.next()?;
+        self.next = i + 2;
+        Some(Line::new(i + 1, l))
+    }
+}
+
+pub fn lines(input: &str) -> Lines<'_> {
+    Lines {
+        lines: input.trim_end().lines().enumerate(),
+        next: 1,
+    }
+}
+
+// groups of lines separated by blank lines
+pub fn blocks(input: &str) -> Vec<Vec<Line<'_>>> {
+    let mut blocks = vec![Vec::new()];
+    for l in lines(input) {
+        match l.trim().is_empty() {
+            true if blocks.last().is_some_and(|b| !b.is_empty()) => blocks.push(Vec::new()),
+            true => {}
+            false => blocks.last_mut().unwrap().push(l),
+        }
+    }
+    blocks.retain(|b| !b.is_empty());
+    blocks
+}
+
+// a rectangle of characters, one value per character
+pub fn rows<'a, T>(
+    lines: impl IntoIterator<Item = Line<'a>>,
+    expected: &str,
+    mut cell: impl FnMut(char) -> Option<T>,
+) -> Result<Vec<Vec<T>>, ParseError> {
+    let mut rows: Vec<Vec<T>> = Vec::new();
+    for l in lines {
+        let row = l.cells(expected, &mut cell)?;
+        if let Some(width) = rows.first().map(Vec::len) {
+            if row.len() != width {
+                let at = l
+                    .text
+                    .char_indices()
+                    .nth(width)
+                    .map_or(l.text.len(), |(i, _)| i);
+                let found = quoted(&l.text[at..].chars().take(1).collect::<String>());
+                return Err(l.error_at(at, found, format!("a row of {} cells", width)));
+            }
+        }
+        rows.push(row);
+    }
+    if rows.is_empty() {
+        return Err(lines_end(1, "a row"));
+    }
+    Ok(rows)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn crlf_and_trailing_blank_lines() {
+        let text: Vec<_> = lines("a b\r\n\r\nc\r\n\r\n\n")
+            .map(|l| l.as_str())
+            .collect();
+        assert_eq!(text, vec!["a b", "", "c"]);
+        let blocks = blocks("a\r\nb\r\n\r\nc\r\n\r\n");
+        assert_eq!(blocks.len(), 2);
+        assert_eq!(blocks[1][0].number(), 4);
+    }
+
+    #[test]
+    fn errors_point_at_the_input() {
+        let l = lines("Game 1: 3 blue\nGame 2: 4 bleu, x red")
+            .nth(1)
+            .unwrap();
+        let (_, rounds) = l.split_once(": ").unwrap();
+        let counts: Vec<_> = rounds
+            .split(", ")
+            .map(|r| r.words().next().unwrap())
+            .collect();
+        assert_eq!(counts[1].parse::<u32>("a number").unwrap_err().column, 17);
+        let e = counts[1].parse::<u32>("a number").unwrap_err().on_day(2);
+        assert_eq!(
+            e.to_string(),
+            "day 02, line 2, column 17: expected a number, found 'x'"
+        );
+        let e = l.split_once(";").unwrap_err();
+        assert_eq!((e.column, e.found.as_str()), (22, "end of line"));
+        let e = l.strip_prefix("Round").unwrap_err();
+        assert_eq!(e.found, "\"Game\"");
+    }
+
+    #[test]
+    fn ragged_rows() {
+        assert_eq!(rows(lines("ab\ncd\n"), "a letter", Some).unwrap().len(), 2);
+        let e = rows(lines("ab\nc\n"), "a letter", Some).unwrap_err();
+        assert_eq!((e.line, e.column, e.found.as_str()), (2, 2, "end of line"));
+        let e = rows(lines("ab\nc1\n"), "a letter", |c| {
+            c.is_alphabetic().then_some(c)
+        })
+        .unwrap_err();
+        assert_eq!((e.line, e.column, e.found.as_str()), (2, 2, "'1'"));
+        let e = rows(lines("\r\n\n"), "a letter", Some).unwrap_err();
+        assert_eq!((e.line, e.found.as_str()), (1, "end of input"));
+    }
+}
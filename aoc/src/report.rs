@@ -0,0 +1,98 @@
+use std::cell::RefCell;
+use std::fmt::Write;
+use std::time::Duration;
+
+use crate::Part;
+
+thread_local! {
+    static WARNINGS: RefCell<Vec<String>> = const { RefCell::new(Vec::new()) };
+}
+
+// something the answer relies on that this input may not satisfy; it is
+// reported next to the answer, on stderr or in the JSON record, never on stdout
+pub fn warn(message: impl Into<String>) {
+    WARNINGS.with(|w| w.borrow_mut().push(message.into()));
+}
+
+// the warnings raised on this thread since the last call
+pub fn take_warnings() -> Vec<String> {
+    WARNINGS.with(|w| w.take())
+}
+
+// the outcome of solving one part of one day
+#[derive(Clone, Debug, PartialEq)]
+pub struct Report {
+    pub day: u8,
+    pub part: Part,
+    // the answer, or why there is none
+    pub answer: Result<String, String>,
+    pub elapsed: Duration,
+    pub warnings: Vec<String>,
+}
+
+fn quote(s: &str) -> String {
+    let mut out = String::from("\"");
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => write!(out, "\\u{:04x}", c as u32).unwrap(),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+impl Report {
+    // one JSON object on one line; the answer is always a string so big numbers survive
+    pub fn to_json(&self) -> String {
+        let (answer, error) = match &self.answer {
+            Ok(answer) => (quote(answer), "null".to_string()),
+            Err(e) => ("null".to_string(), quote(e)),
+        };
+        let warnings: Vec<String> = self.warnings.iter().map(|w| quote(w)).collect();
+        format!(
+            "{{\"day\":{},\"part\":\"{}\",\"answer\":{},\"error\":{},\"elapsed_ms\":{:.3},\"warnings\":[{}]}}",
+            self.day,
+            self.part,
+            answer,
+            error,
+            self.elapsed.as_secs_f64() * 1e3,
+            warnings.join(",")
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn json_record() {
+        warn("assumed \"rx\" is fed by one module");
+        let report = Report {
+            day: 20,
+            part: Part::Two,
+            answer: Ok("238593356738827".to_string()),
+            elapsed: Duration::from_micros(1500),
+            warnings: take_warnings(),
+        };
+        assert_eq!(
+            report.to_json(),
+            r#"{"day":20,"part":"b","answer":"238593356738827","error":null,"elapsed_ms":1.500,"warnings":["assumed \"rx\" is fed by one module"]}"#
+        );
+        assert!(take_warnings().is_empty());
+        let failed = Report {
+            answer: Err("line 1\tcolumn 2".to_string()),
+            warnings: vec![],
+            ..report
+        };
+        assert!(failed
+            .to_json()
+            .contains(r#""answer":null,"error":"line 1\tcolumn 2""#));
+    }
+}
@@ -0,0 +1,228 @@
+use std::collections::BTreeMap;
+use std::fmt::Write;
+
+use aoc::{Line, ParseError, Part, Solution};
+
+// cubes by colour; a colour that is not listed counts as 0
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Bag(BTreeMap<String, u64>);
+
+impl Bag {
+    pub fn count(&self, colour: &str) -> u64 {
+        self.0.get(colour).copied().unwrap_or(0)
+    }
+    pub fn colours(&self) -> impl Iterator<Item = (&str, u64)> {
+        self.0.iter().map(|(c, &n)| (c.as_str(), n))
+    }
+    // whether every cube of other could have come out of this bag
+    pub fn holds(&self, other: &Bag) -> bool {
+        other.colours().all(|(c, n)| n <= self.count(c))
+    }
+    // the smallest bag holding both
+    pub fn union(&mut self, other: &Bag) {
+        for (c, n) in other.colours() {
+            let m = self.0.entry(c.to_string()).or_insert(0);
+            *m = (*m).max(n);
+        }
+    }
+    // the product of the counts of the given colours, or None if it does not
+    // fit in a u128
+    pub fn power<'c>(&self, colours: impl IntoIterator<Item = &'c str>) -> Option<u128> {
+        colours
+            .into_iter()
+            .try_fold(1u128, |p, c| p.checked_mul(self.count(c) as u128))
+    }
+}
+
+impl<S: Into<String>> FromIterator<(S, u64)> for Bag {
+    fn from_iter<I: IntoIterator<Item = (S, u64)>>(iter: I) -> Self {
+        Bag(iter.into_iter().map(|(c, n)| (c.into(), n)).collect())
+    }
+}
+
+// a round showing more cubes of one colour than the bag holds
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Excess {
+    // counting from 1
+    pub round: usize,
+    pub colour: String,
+    pub shown: u64,
+    pub limit: u64,
+}
+
+pub struct Game {
+    pub id: usize,
+    // the cubes shown in each round
+    pub rounds: Vec<Bag>,
+}
+
+impl Game {
+    pub fn possible(&self, bag: &Bag) -> bool {
+        self.rounds.iter().all(|r| bag.holds(r))
+    }
+    // the fewest cubes of each colour that make the game possible
+    pub fn minimal_bag(&self) -> Bag {
+        let mut bag = Bag::default();
+        for r in &self.rounds {
+            bag.union(r);
+        }
+        bag
+    }
+    // every round and colour that makes the game impossible with this bag
+    pub fn excesses(&self, bag: &Bag) -> Vec<Excess> {
+        let mut excesses = Vec::new();
+        for (i, r) in self.rounds.iter().enumerate() {
+            for (c, n) in r.colours().filter(|&(c, n)| n > bag.count(c)) {
+                excesses.push(Excess {
+                    round: i + 1,
+                    colour: c.to_string(),
+                    shown: n,
+                    limit: bag.count(c),
+                });
+            }
+        }
+        excesses
+    }
+    // each colour of the minimal bag with the first round, counting from 1,
+    // that shows that many cubes
+    pub fn minimum_rounds(&self) -> Vec<(String, u64, usize)> {
+        self.minimal_bag()
+            .colours()
+            .map(|(c, n)| {
+                let i = self.rounds.iter().position(|r| r.count(c) == n).unwrap();
+                (c.to_string(), n, i + 1)
+            })
+            .collect()
+    }
+}
+
+// one line per impossible game naming the rounds and colours over the limit
+pub fn impossible_report(games: &[Game], bag: &Bag) -> String {
+    let mut out = String::new();
+    for g in games {
+        for e in g.excesses(bag) {
+            writeln!(
+                out,
+                "game {}: round {} shows {} {}, the bag holds {}",
+                g.id, e.round, e.shown, e.colour, e.limit
+            )
+            .unwrap();
+        }
+    }
+    out
+}
+
+// "3 blue, 4 red"; a colour named twice in one round is added up
+fn round(l: Line) -> Result<Bag, ParseError> {
+    let mut bag = Bag::default();
+    for cubes in l.split(",") {
+        let (n, colour) = cubes.trim().split_once(" ")?;
+        let n: u64 = n.parse("a number of cubes")?;
+        let colour = colour.trim();
+        if colour.is_empty() || !colour.as_str().chars().all(|c| c.is_alphabetic()) {
+            return Err(colour.error("a colour"));
+        }
+        let total = bag.0.entry(colour.as_str().to_string()).or_insert(0);
+        *total = total
+            .checked_add(n)
+            .ok_or_else(|| cubes.trim().error("fewer cubes"))?;
+    }
+    Ok(bag)
+}
+
+// "Game 1: 3 blue, 4 red; 1 red, 2 green"
+pub fn games(input: &str) -> Result<Vec<Game>, ParseError> {
+    let mut games = Vec::new();
+    for l in aoc::lines(input) {
+        let (game, rounds) = l.split_once(": ")?;
+        games.push(Game {
+            id: game.strip_prefix("Game ")?.parse("a game number")?,
+            rounds: rounds.split(";").map(round).collect::<Result<_, _>>()?,
+        });
+    }
+    Ok(games)
+}
+
+pub struct Day02a;
+
+impl Day02a {
+    pub fn bag() -> Bag {
+        [("red", 12), ("green", 13), ("blue", 14)]
+            .into_iter()
+            .collect()
+    }
+}
+
+impl Solution for Day02a {
+    const DAY: u8 = 2;
+    const PART: Part = Part::One;
+    type Input = Vec<Game>;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        games(input)
+    }
+
+    fn solve(games: &Self::Input) -> usize {
+        let bag = Self::bag();
+        games
+            .iter()
+            .filter(|g| g.possible(&bag))
+            .map(|g| g.id)
+            .sum::<usize>()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green
+";
+
+    #[test]
+    fn example() {
+        assert_eq!(aoc::solve::<Day02a>(EXAMPLE), Ok(8));
+    }
+
+    #[test]
+    fn any_colours_and_large_counts() {
+        let g = games("Game 1: 300 red, 2 yellow; 1 red, 1 red\nGame 2: 1 red").unwrap();
+        let bag: Bag = [("red", 300), ("yellow", 2)].into_iter().collect();
+        assert!(g[0].possible(&bag));
+        assert!(!g[0].possible(&Day02a::bag()));
+        assert_eq!(g[0].rounds[1].count("red"), 2);
+        assert_eq!(g[0].minimal_bag(), bag);
+        assert_eq!(g[0].minimal_bag().power(["red", "yellow"]), Some(600));
+        assert_eq!(g[0].minimal_bag().power(["red", "green", "blue"]), Some(0));
+        assert!(g[1].possible(&Day02a::bag()));
+        let e = games("Game 1: 3 blue, 4 42").err().unwrap();
+        assert_eq!((e.column, e.expected.as_str()), (19, "a colour"));
+    }
+
+    #[test]
+    fn impossible_rounds() {
+        let g = games(EXAMPLE).unwrap();
+        assert_eq!(
+            impossible_report(&g, &Day02a::bag()),
+            "\
+game 3: round 1 shows 20 red, the bag holds 12
+game 4: round 3 shows 15 blue, the bag holds 14
+game 4: round 3 shows 14 red, the bag holds 12
+"
+        );
+        assert_eq!(
+            g[0].minimum_rounds(),
+            vec![
+                ("blue".to_string(), 6, 2),
+                ("green".to_string(), 2, 2),
+                ("red".to_string(), 4, 1)
+            ]
+        );
+    }
+}
@@ -0,0 +1,99 @@
+use std::fmt::Write;
+
+use aoc::{Checked, ParseError, Part, Solution};
+use day02a::{Day02a, Game};
+
+// the power of the fewest cubes that make the game possible, over the colours
+// of the puzzle's bag; a colour the game never shows counts as 0
+fn power(g: &Game) -> Option<u128> {
+    g.minimal_bag()
+        .power(Day02a::bag().colours().map(|(c, _)| c))
+}
+
+// one line per game: each colour of the minimal bag, the round that set it and the power
+pub fn power_report(games: &[Game]) -> String {
+    let mut out = String::new();
+    for g in games {
+        let minimums: Vec<String> = g
+            .minimum_rounds()
+            .iter()
+            .map(|(c, n, round)| format!("{} {} from round {}", n, c, round))
+            .collect();
+        let power = power(g).map_or("too large".to_string(), |p| p.to_string());
+        writeln!(
+            out,
+            "game {}: {}, power {}",
+            g.id,
+            minimums.join(", "),
+            power
+        )
+        .unwrap();
+    }
+    out
+}
+
+pub struct Day02b;
+
+impl Solution for Day02b {
+    const DAY: u8 = 2;
+    const PART: Part = Part::Two;
+    type Input = Vec<Game>;
+    type Answer = Checked<u128>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        day02a::games(input)
+    }
+
+    fn solve(games: &Self::Input) -> Checked<u128> {
+        let mut sum = 0u128;
+        for g in games {
+            match power(g).and_then(|p| sum.checked_add(p)) {
+                Some(s) => sum = s,
+                None => {
+                    aoc::warn(format!("the powers overflow a u128 from game {} on", g.id));
+                    return Checked::TooLarge;
+                }
+            }
+        }
+        Checked::Value(sum)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green
+";
+
+    #[test]
+    fn example() {
+        assert_eq!(aoc::solve::<Day02b>(EXAMPLE), Ok(Checked::Value(2286)));
+        // no blue cubes shown, so the power is 0
+        assert_eq!(
+            aoc::solve::<Day02b>("Game 1: 3 red, 2 green\n"),
+            Ok(Checked::Value(0))
+        );
+    }
+
+    #[test]
+    fn minimums_by_round() {
+        let games = aoc::parse::<Day02b>(EXAMPLE).unwrap();
+        assert_eq!(
+            power_report(&games[..1]),
+            "game 1: 6 blue from round 2, 2 green from round 2, 4 red from round 1, power 48\n"
+        );
+    }
+
+    #[test]
+    fn powers_past_u128() {
+        let game = format!("Game 1: {0} red, {0} green, {0} blue\n", u64::MAX);
+        assert_eq!(aoc::solve::<Day02b>(&game), Ok(Checked::TooLarge));
+        assert_eq!(aoc::take_warnings().len(), 1);
+    }
+}
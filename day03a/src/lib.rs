@@ -0,0 +1,247 @@
+use std::collections::BTreeMap;
+use std::fmt::Write;
+
+use aoc::{ParseError, Part, Solution};
+use grid::{Grid, Pos};
+
+pub fn symbol(c: u8) -> bool {
+    !c.is_ascii_digit() && c != b'.'
+}
+
+// a run of digits in one row and the symbols around it
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Number {
+    pub value: u64,
+    // the leftmost digit
+    pub start: Pos,
+    pub len: usize,
+    // row by row, each symbol once
+    pub symbols: Vec<(Pos, u8)>,
+}
+
+impl Number {
+    pub fn next_to(&self, c: u8) -> bool {
+        self.symbols.iter().any(|&(_, s)| s == c)
+    }
+}
+
+// how many numbers a gear needs around it
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Count {
+    Exactly(usize),
+    AtLeast(usize),
+}
+
+// what a gear makes of the numbers around it
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Reduce {
+    Product,
+    Sum,
+    Max,
+}
+
+impl Reduce {
+    // None if the result does not fit in a u128
+    pub fn apply(self, values: impl Iterator<Item = u64>) -> Option<u128> {
+        let mut values = values.map(u128::from);
+        match self {
+            Reduce::Product => values.try_fold(1u128, |p, v| p.checked_mul(v)),
+            Reduce::Sum => values.try_fold(0u128, |s, v| s.checked_add(v)),
+            Reduce::Max => Some(values.max().unwrap_or(0)),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct GearRule<'a> {
+    pub symbols: &'a [u8],
+    pub count: Count,
+    pub reduce: Reduce,
+}
+
+impl GearRule<'_> {
+    // a '*' next to exactly two numbers, worth their product
+    pub const PUZZLE: GearRule<'static> = GearRule {
+        symbols: b"*",
+        count: Count::Exactly(2),
+        reduce: Reduce::Product,
+    };
+
+    fn matches(&self, c: u8, numbers: usize) -> bool {
+        self.symbols.contains(&c)
+            && match self.count {
+                Count::Exactly(n) => numbers == n,
+                Count::AtLeast(n) => numbers >= n,
+            }
+    }
+}
+
+pub struct Schematic {
+    pub grid: Grid<u8>,
+    // in reading order
+    pub numbers: Vec<Number>,
+}
+
+impl Schematic {
+    pub fn parts(&self) -> impl Iterator<Item = &Number> {
+        self.numbers.iter().filter(|n| !n.symbols.is_empty())
+    }
+    // e.g. every number next to a '#'
+    pub fn next_to(&self, c: u8) -> impl Iterator<Item = &Number> {
+        self.numbers.iter().filter(move |n| n.next_to(c))
+    }
+    // every symbol with the numbers around it, which may be none
+    pub fn by_symbol(&self) -> BTreeMap<Pos, (u8, Vec<&Number>)> {
+        let mut symbols: BTreeMap<Pos, (u8, Vec<&Number>)> = self
+            .grid
+            .iter()
+            .filter(|&(_, &c)| symbol(c))
+            .map(|(p, &c)| (p, (c, Vec::new())))
+            .collect();
+        for n in &self.numbers {
+            for (p, _) in &n.symbols {
+                symbols.get_mut(p).unwrap().1.push(n);
+            }
+        }
+        symbols
+    }
+
+    // every symbol that is a gear under the rule, with its ratio
+    pub fn gears(&self, rule: &GearRule) -> Vec<(Pos, Option<u128>)> {
+        self.by_symbol()
+            .into_iter()
+            .filter(|(_, (c, numbers))| rule.matches(*c, numbers.len()))
+            .map(|(p, (_, numbers))| (p, rule.reduce.apply(numbers.iter().map(|n| n.value))))
+            .collect()
+    }
+
+    // one line per number with its span and symbols; rows and columns count from 1
+    pub fn report(&self) -> String {
+        let mut out = String::new();
+        for n in &self.numbers {
+            let Pos(x, y) = n.start;
+            write!(
+                out,
+                "{} at row {}, columns {}-{}",
+                n.value,
+                y + 1,
+                x + 1,
+                x as usize + n.len
+            )
+            .unwrap();
+            match n.symbols.is_empty() {
+                true => out.push_str(", next to no symbol"),
+                false => {
+                    for &(Pos(x, y), c) in &n.symbols {
+                        write!(out, ", next to '{}' at {},{}", c as char, y + 1, x + 1).unwrap();
+                    }
+                }
+            }
+            out.push('\n');
+        }
+        out
+    }
+}
+
+pub fn schematic(input: &str) -> Result<Schematic, ParseError> {
+    let lines: Vec<_> = aoc::lines(input).collect();
+    let rows = aoc::rows(lines.iter().copied(), "a digit, '.' or a symbol", |c| {
+        c.is_ascii_graphic().then_some(c as u8)
+    })?;
+    let grid = Grid::from_rows(rows);
+    let mut numbers = Vec::new();
+    for (y, row) in grid.rows().enumerate() {
+        let mut x = 0;
+        while x < row.len() {
+            let len = row[x..].iter().take_while(|c| c.is_ascii_digit()).count();
+            if len == 0 {
+                x += 1;
+                continue;
+            }
+            let digits = lines[y].piece(&lines[y].as_str()[x..x + len]);
+            let value = digits.parse("a number below 2^64")?;
+            let mut symbols = BTreeMap::new();
+            for dx in 0..len {
+                let p = Pos((x + dx) as i32, y as i32);
+                for q in grid.neighbours8(p).filter(|&q| symbol(grid[q])) {
+                    symbols.insert((q.1, q.0), grid[q]);
+                }
+            }
+            numbers.push(Number {
+                value,
+                start: Pos(x as i32, y as i32),
+                len,
+                symbols: symbols
+                    .into_iter()
+                    .map(|((y, x), c)| (Pos(x, y), c))
+                    .collect(),
+            });
+            x += len;
+        }
+    }
+    Ok(Schematic { grid, numbers })
+}
+
+pub struct Day03a;
+
+impl Solution for Day03a {
+    const DAY: u8 = 3;
+    const PART: Part = Part::One;
+    type Input = Schematic;
+    type Answer = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        schematic(input)
+    }
+
+    fn solve(s: &Schematic) -> u64 {
+        s.parts().map(|n| n.value).sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+467..114..
+...*......
+..35..633.
+......#...
+617*......
+.....+.58.
+..592.....
+......755.
+...$.*....
+.664.598..
+";
+
+    #[test]
+    fn example() {
+        assert_eq!(aoc::solve::<Day03a>(EXAMPLE), Ok(4361));
+    }
+
+    #[test]
+    fn rectangular() {
+        let s = schematic("0.12345\n*....#9\n").unwrap();
+        assert_eq!(
+            s.numbers.iter().map(|n| n.value).collect::<Vec<_>>(),
+            vec![0, 12345, 9]
+        );
+        assert_eq!(Day03a::solve(&s), 12354);
+        assert_eq!(
+            s.next_to(b'#').map(|n| n.value).collect::<Vec<_>>(),
+            vec![12345, 9]
+        );
+        assert_eq!(
+            s.report(),
+            "\
+0 at row 1, columns 1-1, next to '*' at 2,1
+12345 at row 1, columns 3-7, next to '#' at 2,6
+9 at row 2, columns 7-7, next to '#' at 2,6
+"
+        );
+        let e = schematic("..99999999999999999999\n").err().unwrap();
+        assert_eq!((e.column, e.expected.as_str()), (3, "a number below 2^64"));
+    }
+}
@@ -0,0 +1,97 @@
+use aoc::{Checked, ParseError, Part, Solution};
+use day03a::{GearRule, Schematic};
+use grid::Pos;
+
+// the sum of the gear ratios; a ratio or sum too large for a u128 is too large,
+// with a warning naming the gear where it overflows
+pub fn gear_total(s: &Schematic, rule: &GearRule) -> Checked<u128> {
+    let mut total = 0u128;
+    for (Pos(x, y), ratio) in s.gears(rule) {
+        match ratio.and_then(|r| total.checked_add(r)) {
+            Some(t) => total = t,
+            None => {
+                aoc::warn(format!(
+                    "the gear ratios overflow a u128 from row {}, column {} on",
+                    y + 1,
+                    x + 1
+                ));
+                return Checked::TooLarge;
+            }
+        }
+    }
+    Checked::Value(total)
+}
+
+pub struct Day03b;
+
+impl Solution for Day03b {
+    const DAY: u8 = 3;
+    const PART: Part = Part::Two;
+    type Input = Schematic;
+    type Answer = Checked<u128>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        day03a::schematic(input)
+    }
+
+    fn solve(s: &Schematic) -> Checked<u128> {
+        gear_total(s, &GearRule::PUZZLE)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use day03a::{Count, Reduce};
+
+    const EXAMPLE: &str = "\
+467..114..
+...*......
+..35..633.
+......#...
+617*......
+.....+.58.
+..592.....
+......755.
+...$.*....
+.664.598..
+";
+
+    #[test]
+    fn example() {
+        assert_eq!(aoc::solve::<Day03b>(EXAMPLE), Ok(Checked::Value(467835)));
+    }
+
+    #[test]
+    fn ratios_past_u128() {
+        let max = u64::MAX.to_string();
+        let gear = format!("{}*{}\n", max, max);
+        let gap = format!("{}\n", ".".repeat(gear.len() - 1));
+        let one = format!("{}{}", gear, gap);
+        assert_eq!(
+            aoc::solve::<Day03b>(&one),
+            Ok(Checked::Value(u64::MAX as u128 * u64::MAX as u128))
+        );
+        let two = format!("{}{}{}", gear, gap, gear);
+        assert_eq!(aoc::solve::<Day03b>(&two), Ok(Checked::TooLarge));
+        assert_eq!(aoc::take_warnings().len(), 1);
+    }
+
+    #[test]
+    fn other_rules() {
+        let s = aoc::parse::<Day03b>(EXAMPLE).unwrap();
+        let rule = GearRule {
+            symbols: b"*",
+            count: Count::AtLeast(1),
+            reduce: Reduce::Sum,
+        };
+        assert_eq!(gear_total(&s, &rule), Checked::Value(2472));
+        let rule = GearRule {
+            symbols: b"#+$",
+            count: Count::Exactly(1),
+            reduce: Reduce::Max,
+        };
+        assert_eq!(gear_total(&s, &rule), Checked::Value(1889));
+        assert_eq!(s.gears(&rule).len(), 3);
+    }
+}
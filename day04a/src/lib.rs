@@ -0,0 +1,217 @@
+use std::collections::HashSet;
+use std::fmt::Write;
+
+use aoc::{Checked, ParseError, Part, Solution};
+
+pub struct Card {
+    pub id: usize,
+    pub winning: HashSet<u32>,
+    pub have: HashSet<u32>,
+}
+
+impl Card {
+    pub fn matches(&self) -> usize {
+        self.winning.intersection(&self.have).count()
+    }
+    // 1 for the first match, doubled for each further one; None past u128
+    pub fn points(&self) -> Option<u128> {
+        match self.matches() {
+            0 => Some(0),
+            m => 1u128.checked_shl(m as u32 - 1),
+        }
+    }
+}
+
+// "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53"
+pub fn cards(input: &str) -> Result<Vec<Card>, ParseError> {
+    aoc::lines(input)
+        .map(|l| {
+            let (card, numbers) = l.split_once(":")?;
+            let (winning, have) = numbers.split_once("|")?;
+            Ok(Card {
+                id: card.strip_prefix("Card")?.parse("a card number")?,
+                winning: HashSet::from_iter(winning.values::<u32>("a number")?),
+                have: HashSet::from_iter(have.values::<u32>("a number")?),
+            })
+        })
+        .collect()
+}
+
+// one card after the copies have cascaded down the table
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Tally {
+    pub id: usize,
+    pub matches: usize,
+    pub points: Option<u128>,
+    // the original and every copy won; None past u128
+    pub copies: Option<u128>,
+}
+
+// a card whose matches reach past the last card
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Spill {
+    pub id: usize,
+    // how many of the cards it wins copies of do not exist
+    pub missing: usize,
+}
+
+pub struct Cascade {
+    pub cards: Vec<Tally>,
+    pub spills: Vec<Spill>,
+}
+
+impl Cascade {
+    pub fn new(cards: &[Card]) -> Self {
+        let mut tallies: Vec<Tally> = cards
+            .iter()
+            .map(|c| Tally {
+                id: c.id,
+                matches: c.matches(),
+                points: c.points(),
+                copies: Some(1),
+            })
+            .collect();
+        let mut spills = Vec::new();
+        for i in 0..tallies.len() {
+            let Tally {
+                id,
+                matches,
+                copies,
+                ..
+            } = tallies[i];
+            let last = tallies.len() - 1;
+            if i + matches > last {
+                spills.push(Spill {
+                    id,
+                    missing: i + matches - last,
+                });
+            }
+            for won in &mut tallies[i + 1..=(i + matches).min(last)] {
+                won.copies = won.copies.zip(copies).and_then(|(a, b)| a.checked_add(b));
+            }
+        }
+        Cascade {
+            cards: tallies,
+            spills,
+        }
+    }
+
+    // every card held at the end; None past u128
+    pub fn total(&self) -> Option<u128> {
+        self.cards
+            .iter()
+            .try_fold(0u128, |t, c| c.copies.and_then(|n| t.checked_add(n)))
+    }
+
+    // one line per card, then one per spill
+    pub fn report(&self) -> String {
+        let big = |n: Option<u128>| n.map_or("more than 2^128".to_string(), |n| n.to_string());
+        let mut out = String::new();
+        for c in &self.cards {
+            writeln!(
+                out,
+                "card {}: {} matches, {} points, {} copies",
+                c.id,
+                c.matches,
+                big(c.points),
+                big(c.copies)
+            )
+            .unwrap();
+        }
+        for s in &self.spills {
+            writeln!(out, "{}", s).unwrap();
+        }
+        out
+    }
+}
+
+impl std::fmt::Display for Spill {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "card {} wins copies of cards up to {} past the last one, which are dropped",
+            self.id, self.missing
+        )
+    }
+}
+
+pub struct Day04a;
+
+impl Solution for Day04a {
+    const DAY: u8 = 4;
+    const PART: Part = Part::One;
+    type Input = Vec<Card>;
+    type Answer = Checked<u128>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        cards(input)
+    }
+
+    fn solve(cards: &Self::Input) -> Checked<u128> {
+        cards
+            .iter()
+            .try_fold(0u128, |t, c| c.points().and_then(|p| t.checked_add(p)))
+            .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11
+";
+
+    #[test]
+    fn example() {
+        assert_eq!(aoc::solve::<Day04a>(EXAMPLE), Ok(Checked::Value(13)));
+    }
+
+    #[test]
+    fn points_past_u128() {
+        // 128 matches are worth 2^127 points, so two such cards overflow
+        let numbers: String = (1..=128).map(|n| format!(" {}", n)).collect();
+        let card = |id| format!("Card {}:{} |{}\n", id, numbers, numbers);
+        assert_eq!(aoc::solve::<Day04a>(&card(1)), Ok(Checked::Value(1 << 127)));
+        let two = format!("{}{}", card(1), card(2));
+        assert_eq!(aoc::solve::<Day04a>(&two), Ok(Checked::TooLarge));
+    }
+
+    #[test]
+    fn cascade() {
+        let cascade = Cascade::new(&cards(EXAMPLE).unwrap());
+        assert_eq!(
+            cascade.cards.iter().map(|c| c.copies).collect::<Vec<_>>(),
+            [1, 2, 4, 8, 14, 1].map(Some)
+        );
+        assert_eq!(cascade.cards[0].points, Some(8));
+        assert!(cascade.spills.is_empty());
+
+        let cards = cards("Card 1: 1 2 | 1 2\nCard 2: 3 4 | 3 5\n").unwrap();
+        let cascade = Cascade::new(&cards);
+        assert_eq!(
+            cascade.spills,
+            vec![Spill { id: 1, missing: 1 }, Spill { id: 2, missing: 1 }]
+        );
+        assert_eq!(cascade.total(), Some(3));
+    }
+
+    #[test]
+    fn copies_past_u128() {
+        // two matches each, so the copies grow like the Fibonacci numbers: F(n + 2) - 1
+        let text: String = (1..=200)
+            .map(|i| format!("Card {}: 1 2 | 1 2\n", i))
+            .collect();
+        let cascade = Cascade::new(&cards(&text).unwrap());
+        assert_eq!(cascade.cards[10].copies, Some(232));
+        assert_eq!(cascade.cards[199].copies, None);
+        assert_eq!(cascade.total(), None);
+        assert_eq!(cascade.spills.len(), 2);
+    }
+}
@@ -0,0 +1,51 @@
+use aoc::{Checked, ParseError, Part, Solution};
+use day04a::{Card, Cascade};
+
+pub struct Day04b;
+
+impl Solution for Day04b {
+    const DAY: u8 = 4;
+    const PART: Part = Part::Two;
+    type Input = Vec<Card>;
+    type Answer = Checked<u128>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        day04a::cards(input)
+    }
+
+    fn solve(cards: &Self::Input) -> Checked<u128> {
+        let cascade = Cascade::new(cards);
+        for s in &cascade.spills {
+            aoc::warn(s.to_string());
+        }
+        cascade.total().into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11
+";
+
+    #[test]
+    fn example() {
+        assert_eq!(aoc::solve::<Day04b>(EXAMPLE), Ok(Checked::Value(30)));
+    }
+
+    #[test]
+    fn copies_past_u128() {
+        let text: String = (1..=200)
+            .map(|i| format!("Card {}: 1 2 | 1 2\n", i))
+            .collect();
+        assert_eq!(aoc::solve::<Day04b>(&text), Ok(Checked::TooLarge));
+        assert_eq!(aoc::take_warnings().len(), 2);
+    }
+}
@@ -0,0 +1,374 @@
+use std::fmt::Write;
+use std::ops::Range;
+
+use aoc::{Line, ParseError, Part, Solution};
+
+// src_start..src_end goes to dst_start.. in order
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct MapEntry {
+    pub src_start: u64,
+    pub src_end: u64,
+    pub dst_start: u64,
+}
+
+impl MapEntry {
+    fn len(&self) -> u64 {
+        self.src_end - self.src_start
+    }
+}
+
+// a piecewise-linear map on 0..u64::MAX; values outside every entry map to themselves
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct RangeMap {
+    // sorted by source and not overlapping
+    entries: Vec<MapEntry>,
+}
+
+impl RangeMap {
+    // Err(i) if entries[i] overlaps another entry or maps past u64::MAX
+    pub fn new(entries: &[MapEntry]) -> Result<Self, usize> {
+        let mut order: Vec<usize> = (0..entries.len())
+            .filter(|&i| entries[i].len() > 0)
+            .collect();
+        order.sort_by_key(|&i| entries[i].src_start);
+        if let Some(&i) = order
+            .iter()
+            .find(|&&i| entries[i].dst_start.checked_add(entries[i].len()).is_none())
+        {
+            return Err(i);
+        }
+        for w in order.windows(2) {
+            if entries[w[0]].src_end > entries[w[1]].src_start {
+                return Err(w[0].max(w[1]));
+            }
+        }
+        Ok(RangeMap {
+            entries: order.into_iter().map(|i| entries[i]).collect(),
+        })
+    }
+    pub fn entries(&self) -> &[MapEntry] {
+        &self.entries
+    }
+
+    pub fn get(&self, x: u64) -> u64 {
+        let i = self.entries.partition_point(|e| e.src_end <= x);
+        match self.entries.get(i) {
+            Some(e) if e.src_start <= x => e.dst_start + (x - e.src_start),
+            _ => x,
+        }
+    }
+
+    // r cut where the entries start and end, each piece with where it starts after
+    // the map, in order
+    pub fn pieces(&self, r: Range<u64>) -> impl Iterator<Item = (Range<u64>, u64)> + '_ {
+        let mut at = r.start;
+        let mut entries = self.entries[self.entries.partition_point(|e| e.src_end <= at)..]
+            .iter()
+            .peekable();
+        std::iter::from_fn(move || {
+            if at >= r.end {
+                return None;
+            }
+            let start = at;
+            let piece = match entries.peek() {
+                Some(e) if e.src_start <= at => {
+                    at = e.src_end.min(r.end);
+                    let dst = e.dst_start + (start - e.src_start);
+                    entries.next();
+                    dst
+                }
+                Some(e) => {
+                    at = e.src_start.min(r.end);
+                    start
+                }
+                None => {
+                    at = r.end;
+                    start
+                }
+            };
+            Some((start..at, piece))
+        })
+    }
+
+    // where the values of r end up, one range per piece
+    pub fn get_range(&self, r: Range<u64>) -> impl Iterator<Item = Range<u64>> + '_ {
+        self.pieces(r)
+            .map(|(src, dst)| dst..dst + (src.end - src.start))
+    }
+
+    // every value that lands in r, as sorted ranges
+    pub fn preimage(&self, r: Range<u64>) -> Vec<Range<u64>> {
+        let mut found = Vec::new();
+        for (src, dst) in self.pieces(0..u64::MAX) {
+            let end = dst + (src.end - src.start);
+            let (lo, hi) = (dst.max(r.start), end.min(r.end));
+            if lo < hi {
+                found.push(src.start + (lo - dst)..src.start + (hi - dst));
+            }
+        }
+        merge(found)
+    }
+
+    // the map taking x to next.get(self.get(x))
+    pub fn then(&self, next: &RangeMap) -> RangeMap {
+        let mut entries = Vec::new();
+        for (src, dst) in self.pieces(0..u64::MAX) {
+            for (mid, end) in next.pieces(dst..dst + (src.end - src.start)) {
+                let start = src.start + (mid.start - dst);
+                if start != end {
+                    entries.push(MapEntry {
+                        src_start: start,
+                        src_end: start + (mid.end - mid.start),
+                        dst_start: end,
+                    });
+                }
+            }
+        }
+        RangeMap { entries }
+    }
+}
+
+// sorted, with touching and overlapping ranges joined
+fn merge(mut ranges: Vec<Range<u64>>) -> Vec<Range<u64>> {
+    ranges.sort_by_key(|r| r.start);
+    let mut merged: Vec<Range<u64>> = Vec::new();
+    for r in ranges {
+        match merged.last_mut() {
+            Some(last) if last.end >= r.start => last.end = last.end.max(r.end),
+            _ => merged.push(r),
+        }
+    }
+    merged
+}
+
+// "seed-to-soil map:" and its entries
+pub struct Stage {
+    pub from: String,
+    pub to: String,
+    pub map: RangeMap,
+}
+
+pub struct Almanac {
+    pub seeds: Vec<u64>,
+    pub stages: Vec<Stage>,
+}
+
+impl Almanac {
+    // every stage folded into one, seed to location
+    pub fn map(&self) -> RangeMap {
+        self.stages
+            .iter()
+            .fold(RangeMap::default(), |map, stage| map.then(&stage.map))
+    }
+
+    // the values of every category, from location back to seed, that lead into r
+    pub fn trace_back(&self, r: Range<u64>) -> Vec<(&str, Vec<Range<u64>>)> {
+        let Some(last) = self.stages.last() else {
+            return vec![("seed", vec![r])];
+        };
+        let mut trace = vec![(last.to.as_str(), vec![r])];
+        for stage in self.stages.iter().rev() {
+            let ranges = trace.last().unwrap().1.iter();
+            let ranges = merge(ranges.flat_map(|r| stage.map.preimage(r.clone())).collect());
+            trace.push((stage.from.as_str(), ranges));
+        }
+        trace
+    }
+
+    // one line per category of trace_back
+    pub fn explain(&self, r: Range<u64>) -> String {
+        let mut out = String::new();
+        for (category, ranges) in self.trace_back(r) {
+            let ranges: Vec<String> = ranges
+                .iter()
+                .map(|r| format!("{}..{}", r.start, r.end))
+                .collect();
+            writeln!(out, "{}: {}", category, ranges.join(", ")).unwrap();
+        }
+        out
+    }
+}
+
+// "destination source length"
+fn entry(l: Line) -> Result<MapEntry, ParseError> {
+    match l.values::<u64>("a number")?[..] {
+        [dst, src, len] if src.checked_add(len).is_some() && dst.checked_add(len).is_some() => {
+            Ok(MapEntry {
+                src_start: src,
+                src_end: src + len,
+                dst_start: dst,
+            })
+        }
+        [_, _, _] => Err(l.error("ranges that end below 2^64")),
+        _ => Err(l.error("three numbers: destination, source and length")),
+    }
+}
+
+fn stage(header: Line, lines: &[Line]) -> Result<Stage, ParseError> {
+    let (from, to) = header.strip_suffix(" map:")?.split_once("-to-")?;
+    let entries = lines
+        .iter()
+        .map(|&l| entry(l))
+        .collect::<Result<Vec<_>, _>>()?;
+    let map =
+        RangeMap::new(&entries).map_err(|i| lines[i].error("a range overlapping no other"))?;
+    Ok(Stage {
+        from: from.as_str().to_string(),
+        to: to.as_str().to_string(),
+        map,
+    })
+}
+
+pub fn almanac(input: &str) -> Result<Almanac, ParseError> {
+    let mut lines = aoc::lines(input);
+    let numbers = lines.expect("a \"seeds:\" line")?.strip_prefix("seeds:")?;
+    let seeds: Vec<u64> = numbers.values("a number")?;
+    if seeds.is_empty() {
+        return Err(numbers.error_at_end("a seed"));
+    }
+    // each header with the entries under it
+    let mut stages: Vec<(Line, Vec<Line>)> = Vec::new();
+    for l in lines.filter(|l| !l.trim().is_empty()) {
+        if l.as_str().ends_with(" map:") {
+            stages.push((l, Vec::new()));
+            continue;
+        }
+        let (_, entries) = stages
+            .last_mut()
+            .ok_or_else(|| l.error("a \"... map:\" header"))?;
+        entries.push(l);
+    }
+    let stages = stages
+        .iter()
+        .map(|(header, entries)| stage(*header, entries))
+        .collect::<Result<_, _>>()?;
+    Ok(Almanac { seeds, stages })
+}
+
+pub struct Day05a;
+
+impl Solution for Day05a {
+    const DAY: u8 = 5;
+    const PART: Part = Part::One;
+    type Input = Almanac;
+    type Answer = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        almanac(input)
+    }
+
+    fn solve(almanac: &Self::Input) -> u64 {
+        let map = almanac.map();
+        almanac.seeds.iter().map(|&s| map.get(s)).min().unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+seeds: 79 14 55 13
+
+seed-to-soil map:
+50 98 2
+52 50 48
+
+soil-to-fertilizer map:
+0 15 37
+37 52 2
+39 0 15
+
+fertilizer-to-water map:
+49 53 8
+0 11 42
+42 0 7
+57 7 4
+
+water-to-light map:
+88 18 7
+18 25 70
+
+light-to-temperature map:
+45 77 23
+81 45 19
+68 64 13
+
+temperature-to-humidity map:
+0 69 1
+1 0 69
+
+humidity-to-location map:
+60 56 37
+56 93 4
+";
+
+    #[test]
+    fn example() {
+        assert_eq!(aoc::solve::<Day05a>(EXAMPLE), Ok(35));
+    }
+
+    #[test]
+    fn composed_stages() {
+        let almanac = almanac(EXAMPLE).unwrap();
+        let map = almanac.map();
+        for x in 0..120 {
+            let stepwise = almanac.stages.iter().fold(x, |x, s| s.map.get(x));
+            assert_eq!(map.get(x), stepwise, "seed {}", x);
+        }
+        assert_eq!(map.get(u64::MAX - 1), u64::MAX - 1);
+        let seed_to_soil = &almanac.stages[0].map;
+        assert_eq!(
+            seed_to_soil.get_range(45..100).collect::<Vec<_>>(),
+            vec![45..50, 52..100, 50..52]
+        );
+        assert_eq!(
+            (
+                almanac.stages[6].from.as_str(),
+                almanac.stages[6].to.as_str()
+            ),
+            ("humidity", "location")
+        );
+    }
+
+    #[test]
+    fn back_to_the_seeds() {
+        let almanac = almanac(EXAMPLE).unwrap();
+        assert_eq!(
+            almanac.explain(46..47),
+            "\
+location: 46..47
+humidity: 46..47
+temperature: 45..46
+light: 77..78
+water: 84..85
+fertilizer: 84..85
+soil: 84..85
+seed: 82..83
+"
+        );
+        let map = almanac.map();
+        let (_, seeds) = almanac.trace_back(50..60).pop().unwrap();
+        for x in 0..200 {
+            let inside = seeds.iter().any(|r| r.contains(&x));
+            assert_eq!(inside, (50..60).contains(&map.get(x)), "seed {}", x);
+        }
+    }
+
+    #[test]
+    fn big_and_overlapping_entries() {
+        let big = "seeds: 5000000000\n\nseed-to-soil map:\n1 4999999999 2\n";
+        assert_eq!(aoc::solve::<Day05a>(big), Ok(2));
+        let e = almanac("seeds: 1\n\nseed-to-soil map:\n1 4 2\n7 5 2\n")
+            .err()
+            .unwrap();
+        assert_eq!(
+            (e.line, e.expected.as_str()),
+            (5, "a range overlapping no other")
+        );
+        let e = almanac("seeds: 1\n\nseed-to-soil map:\n1 18446744073709551615 2\n")
+            .err()
+            .unwrap();
+        assert_eq!(e.expected, "ranges that end below 2^64");
+    }
+}
@@ -0,0 +1,100 @@
+use aoc::{ParseError, Part, Solution};
+use day05a::Almanac;
+
+pub struct Day05b;
+
+impl Solution for Day05b {
+    const DAY: u8 = 5;
+    const PART: Part = Part::Two;
+    type Input = Almanac;
+    type Answer = u64;
+
+    // the seeds come in pairs of start and length
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let almanac = day05a::almanac(input)?;
+        let seeds = aoc::lines(input).next().unwrap().strip_prefix("seeds:")?;
+        if almanac.seeds.len() % 2 == 1 {
+            return Err(seeds.error_at_end("a range length after the last seed"));
+        }
+        let words: Vec<_> = seeds.words().collect();
+        for (pair, words) in almanac.seeds.chunks(2).zip(words.chunks(2)) {
+            if pair[1] == 0 {
+                return Err(words[1].error("a range length above 0"));
+            }
+            if pair[0].checked_add(pair[1]).is_none() {
+                return Err(words[0].error("a seed range that ends below 2^64"));
+            }
+        }
+        Ok(almanac)
+    }
+
+    fn solve(almanac: &Self::Input) -> u64 {
+        let map = almanac.map();
+        almanac
+            .seeds
+            .chunks(2)
+            .flat_map(|pair| map.get_range(pair[0]..pair[0] + pair[1]))
+            .map(|r| r.start)
+            .min()
+            .unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+seeds: 79 14 55 13
+
+seed-to-soil map:
+50 98 2
+52 50 48
+
+soil-to-fertilizer map:
+0 15 37
+37 52 2
+39 0 15
+
+fertilizer-to-water map:
+49 53 8
+0 11 42
+42 0 7
+57 7 4
+
+water-to-light map:
+88 18 7
+18 25 70
+
+light-to-temperature map:
+45 77 23
+81 45 19
+68 64 13
+
+temperature-to-humidity map:
+0 69 1
+1 0 69
+
+humidity-to-location map:
+60 56 37
+56 93 4
+";
+
+    #[test]
+    fn example() {
+        assert_eq!(aoc::solve::<Day05b>(EXAMPLE), Ok(46));
+    }
+
+    #[test]
+    fn seed_pairs() {
+        let e = aoc::parse::<Day05b>("seeds: 1 2 3\n").err().unwrap();
+        assert_eq!(e.expected, "a range length after the last seed");
+        let e = aoc::parse::<Day05b>("seeds: 1 2 18446744073709551615 2\n")
+            .err()
+            .unwrap();
+        assert_eq!(
+            (e.column, e.expected.as_str()),
+            (12, "a seed range that ends below 2^64")
+        );
+    }
+}
@@ -1,89 +1,37 @@
-use itertools::Itertools;
-use std::collections::HashSet;
+use std::ops::Range;
 
-#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
-struct MapEntry {
-    src_start: u32,
-    src_end: u32,
-    dst_start: u32,
-}
+use aoc::Solution;
+use day05b::Day05b;
+
+// flag naming the locations to trace back, as "--locations=46..57" or "--locations=46"
+const LOCATIONS_FLAG: &str = "--locations";
 
-macro_rules! range_ok {
-    ( $( $name:ident )+ ) => {
-        ($($name.0)+) < ($($name.1)+)
+fn locations(text: &str) -> Option<Range<u64>> {
+    match text.split_once("..") {
+        Some((start, end)) => Some(start.parse().ok()?..end.parse().ok()?),
+        None => text
+            .parse()
+            .ok()
+            .and_then(|l: u64| Some(l..l.checked_add(1)?)),
     }
 }
 
-fn convert(x: (u32, u32), map: HashSet<MapEntry>) -> HashSet<(u32, u32)> {
-    let mut done = HashSet::new();
-    let mut rest: HashSet<(u32, u32)> = HashSet::from_iter(vec![x]);
-    map.iter().for_each(|e| {
-        let mut curr_rest = HashSet::new();
-        for (start, end) in rest.clone() {
-            let ends = vec![(start, end.min(e.src_start)), ((e.src_end).max(start), end)];
-            ends.iter()
-                .filter(|&range| range_ok!(range))
-                .for_each(|&range| {
-                    curr_rest.insert(range);
-                });
-            let mid = (start.max(e.src_start), (e.src_end).min(end));
-            if range_ok!(mid) {
-                done.insert((
-                    mid.0 - (e.src_start - e.dst_start),
-                    mid.1 - (e.src_start - e.dst_start),
-                ));
+// with --report, the seeds and the values of every category in between that lead
+// to the lowest location, or to the locations given with --locations
+fn main() {
+    aoc::run_or_report::<Day05b>(|almanac| {
+        let range = match aoc::flag_value(LOCATIONS_FLAG) {
+            Some(text) => locations(&text).unwrap_or_else(|| {
+                aoc::exit(format!(
+                    "expected a location or a range such as 46..57 after {}=, found {:?}",
+                    LOCATIONS_FLAG, text
+                ))
+            }),
+            None => {
+                let lowest = Day05b::solve(almanac);
+                lowest..lowest + 1
             }
-        }
-        rest = curr_rest;
+        };
+        almanac.explain(range)
     });
-    done.extend(rest);
-    done
-}
-
-fn main() {
-    let input = include_str!("/tmp/input.txt").trim_end();
-    let seeds: Vec<(u32, u32)> = input
-        .lines()
-        .nth(0)
-        .unwrap()
-        .split_whitespace()
-        .skip(1)
-        .map(|x| x.parse().unwrap())
-        .tuples()
-        .map(|(a, b)| (a, a + b))
-        .collect();
-    let stages: Vec<HashSet<MapEntry>> = input
-        .split("\n\n")
-        .skip(1)
-        .map(|block| {
-            HashSet::from_iter(block.split('\n').skip(1).map(|line| {
-                let val: Vec<u32> = line
-                    .split_ascii_whitespace()
-                    .map(|x| x.parse().unwrap())
-                    .collect();
-                MapEntry {
-                    src_start: val[1],
-                    src_end: val[1] + val[2],
-                    dst_start: val[0],
-                }
-            }))
-        })
-        .collect();
-    println!(
-        "{}",
-        seeds
-            .iter()
-            .flat_map(|&seed| {
-                stages.iter().fold(
-                    HashSet::from_iter(vec![seed]),
-                    |acc: HashSet<(u32, u32)>, stage| {
-                        acc.iter()
-                            .flat_map(|&seed| convert(seed, stage.clone()))
-                            .collect()
-                    },
-                )
-            })
-            .min()
-            .unwrap().0
-    );
 }
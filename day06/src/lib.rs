@@ -0,0 +1,267 @@
+use aoc::{Checked, Line, ParseError, Part, Solution};
+
+// whether holding the button for hold ms beats the record; a distance past u128
+// beats any record
+fn beats(time: u128, record: u128, hold: u128) -> bool {
+    hold.checked_mul(time - hold).is_none_or(|d| d > record)
+}
+
+// a * b as the high and low halves of 256 bits
+fn wide_mul(a: u128, b: u128) -> (u128, u128) {
+    const LOW: u128 = u64::MAX as u128;
+    let (a1, a0, b1, b0) = (a >> 64, a & LOW, b >> 64, b & LOW);
+    let (p00, p01, p10, p11) = (a0 * b0, a0 * b1, a1 * b0, a1 * b1);
+    let mid = (p00 >> 64) + (p01 & LOW) + (p10 & LOW);
+    (
+        p11 + (p01 >> 64) + (p10 >> 64) + (mid >> 64),
+        mid << 64 | p00 & LOW,
+    )
+}
+
+// the integer square root of high * 2^128 + low, a bit of the root at a time
+// once it no longer fits in a u128
+fn wide_isqrt(high: u128, low: u128) -> u128 {
+    if high == 0 {
+        return low.isqrt();
+    }
+    let mut root = 0u128;
+    for bit in (0..128).rev() {
+        let candidate = root | 1 << bit;
+        if wide_mul(candidate, candidate) <= (high, low) {
+            root = candidate;
+        }
+    }
+    root
+}
+
+// the number of hold times that beat the record: the x with x * (time - x) > record.
+// with h = time / 2 and x = h - y the distance is h^2 - y^2, or h(h + 1) - y(y + 1)
+// for an odd time, so y is about the square root of the discriminant h^2 - record;
+// that is worked in 256 bits and corrected for rounding, so a root landing exactly
+// on the record does not count
+pub fn ways(time: u128, record: u128) -> u128 {
+    let half = time / 2;
+    let (high, low) = wide_mul(half, half + time % 2);
+    let (low, borrow) = low.overflowing_sub(record);
+    let Some(high) = high.checked_sub(borrow as u128) else {
+        return 0;
+    };
+    let mut low = half - wide_isqrt(high, low).min(half);
+    while low > 0 && beats(time, record, low - 1) {
+        low -= 1;
+    }
+    while low <= half && !beats(time, record, low) {
+        low += 1;
+    }
+    match low <= half {
+        // the winning holds are symmetric: low..=time - low
+        true => time - 2 * low + 1,
+        false => 0,
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Race {
+    pub time: u128,
+    pub record: u128,
+}
+
+impl Race {
+    pub fn ways(&self) -> u128 {
+        ways(self.time, self.record)
+    }
+}
+
+// both readings of the sheet: one race per column, or the digits of each line
+// read as one number, ignoring the spaces between them. only part two reads the
+// joined numbers, so a sheet whose joined digits do not fit keeps that error there
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Sheet {
+    pub races: Vec<Race>,
+    pub kerned: Result<Race, ParseError>,
+}
+
+type Numbers<'a> = (Vec<u128>, Vec<Line<'a>>, Result<u128, ParseError>);
+
+// the numbers after the label, and the same digits read as one number
+fn numbers<'a>(l: Line<'a>, label: &str) -> Result<Numbers<'a>, ParseError> {
+    let digits = l.trim().strip_prefix(label)?;
+    let values = digits.values::<u128>("a number")?;
+    if values.is_empty() {
+        return Err(digits.error_at_end("a number"));
+    }
+    let joined: String = digits.as_str().split_ascii_whitespace().collect();
+    let kerned = joined
+        .parse()
+        .map_err(|_| digits.trim().error("a number below 2^128"));
+    Ok((values, digits.words().collect(), kerned))
+}
+
+// "Time:      7  15   30" then "Distance:  9  40  200"
+pub fn sheet(input: &str) -> Result<Sheet, ParseError> {
+    let mut lines = aoc::lines(input);
+    let time = lines.expect("a \"Time:\" line")?;
+    let (times, _, kerned_time) = numbers(time, "Time:")?;
+    let distance = lines.expect("a \"Distance:\" line")?;
+    let (records, words, kerned_record) = numbers(distance, "Distance:")?;
+    if let Some(extra) = lines.next() {
+        return Err(extra.error("the end of the sheet"));
+    }
+    if records.len() > times.len() {
+        return Err(words[times.len()].error(format!("{} distances, one per time", times.len())));
+    }
+    if records.len() < times.len() {
+        return Err(distance.error_at_end(format!("{} distances, one per time", times.len())));
+    }
+    Ok(Sheet {
+        races: times
+            .into_iter()
+            .zip(records)
+            .map(|(time, record)| Race { time, record })
+            .collect(),
+        kerned: kerned_time.and_then(|time| {
+            Ok(Race {
+                time,
+                record: kerned_record?,
+            })
+        }),
+    })
+}
+
+pub struct Day06a;
+
+impl Solution for Day06a {
+    const DAY: u8 = 6;
+    const PART: Part = Part::One;
+    type Input = Sheet;
+    type Answer = Checked<u128>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        sheet(input)
+    }
+
+    fn solve(sheet: &Sheet) -> Checked<u128> {
+        sheet
+            .races
+            .iter()
+            .try_fold(1u128, |p, r| p.checked_mul(r.ways()))
+            .into()
+    }
+}
+
+pub struct Day06b;
+
+impl Solution for Day06b {
+    const DAY: u8 = 6;
+    const PART: Part = Part::Two;
+    type Input = Race;
+    type Answer = u128;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        sheet(input)?.kerned
+    }
+
+    fn solve(race: &Race) -> u128 {
+        race.ways()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+Time:      7  15   30
+Distance:  9  40  200
+";
+
+    #[test]
+    fn example() {
+        assert_eq!(aoc::solve::<Day06a>(EXAMPLE), Ok(Checked::Value(288)));
+        assert_eq!(aoc::solve::<Day06b>(EXAMPLE), Ok(71503));
+    }
+
+    #[test]
+    fn kerned_past_u64() {
+        let s = sheet("Time: 1 0000000000 0000000000\nDistance: 1 0 0\n").unwrap();
+        let race = s.kerned.unwrap();
+        assert_eq!(race.time, 10u128.pow(20));
+        assert_eq!(race.ways(), 10u128.pow(20) - 1);
+    }
+
+    #[test]
+    fn malformed_sheets() {
+        let e = sheet("Time: 7 15\nDistance: 9\n").unwrap_err();
+        assert_eq!((e.line, e.column, e.found.as_str()), (2, 12, "end of line"));
+        assert_eq!(e.expected, "2 distances, one per time");
+        let e = sheet("Time: 7\nDistance: 9 40\n").unwrap_err();
+        assert_eq!((e.line, e.column, e.found.as_str()), (2, 13, "\"40\""));
+        let e = sheet("Time: 7\nRecord: 9\n").unwrap_err();
+        assert_eq!((e.line, e.expected.as_str()), (2, "\"Distance:\""));
+        let e = sheet("Distance: 9\n").unwrap_err();
+        assert_eq!((e.line, e.expected.as_str()), (1, "\"Time:\""));
+        let e = sheet("Time: 7\n").unwrap_err();
+        assert_eq!((e.line, e.found.as_str()), (2, "end of input"));
+
+        // joined digits past 2^64 are fine, and past 2^128 only part two fails
+        let sheet = "Time: 50 50 50 50 50 50\nDistance: 1000 1000 1000 1000 1000 1000\n";
+        assert_eq!(aoc::solve::<Day06a>(sheet), Ok(Checked::Value(0)));
+        assert_eq!(aoc::solve::<Day06b>(sheet), Ok(0));
+        let sheet = format!(
+            "Time:{}\nDistance:{}\n",
+            " 50 2".repeat(10),
+            " 100 0".repeat(10)
+        );
+        assert_eq!(
+            aoc::solve::<Day06a>(&sheet),
+            Ok(Checked::Value(45u128.pow(10)))
+        );
+        let e = aoc::solve::<Day06b>(&sheet).unwrap_err();
+        assert_eq!((e.line, e.expected.as_str()), (2, "a number below 2^128"));
+    }
+
+    #[test]
+    fn product_past_u128() {
+        let sheet = format!("Time: {} {}\nDistance: 0 0\n", u128::MAX, u128::MAX);
+        assert_eq!(aoc::solve::<Day06a>(&sheet), Ok(Checked::TooLarge));
+    }
+
+    #[test]
+    fn closed_form_matches_brute_force() {
+        for time in 0..60 {
+            for record in 0..=time * time / 4 + 1 {
+                let brute = (0..=time).filter(|&x| x * (time - x) > record).count();
+                assert_eq!(ways(time, record), brute as u128, "{} {}", time, record);
+            }
+        }
+    }
+
+    #[test]
+    fn large_races() {
+        // roots 10 and 20 land exactly on the record
+        assert_eq!(ways(30, 200), 9);
+        assert_eq!(ways(u64::MAX as u128, 0), u64::MAX as u128 - 1);
+        assert_eq!(ways(u128::MAX, 0), u128::MAX - 1);
+        // 1 * (time - 1) is short of the record, 2 * (time - 2) is past u128
+        assert_eq!(ways(u128::MAX, u128::MAX), u128::MAX - 3);
+        assert_eq!(ways(u128::MAX, u128::MAX - 2), u128::MAX - 1);
+        // only the exact middle beats a record one short of the best
+        assert_eq!(ways(1 << 64, (1 << 126) - 1), 1);
+        assert_eq!(ways(1 << 64, 1 << 126), 0);
+        // an odd time has two middle holds, worth h(h + 1)
+        let h = u64::MAX as u128;
+        assert_eq!(ways(2 * h + 1, h * (h + 1) - 1), 2);
+        assert_eq!(ways(2 * h + 1, h * (h + 1)), 0);
+    }
+
+    #[test]
+    fn wide_square_roots() {
+        for r in [1 << 64, (1 << 64) + 1, (1 << 127) + 12345, u128::MAX] {
+            let (high, low) = wide_mul(r, r);
+            assert_eq!(wide_isqrt(high, low), r);
+            let (low, borrow) = low.overflowing_sub(1);
+            assert_eq!(wide_isqrt(high - borrow as u128, low), r - 1);
+        }
+        assert_eq!(wide_mul(u128::MAX, 2), (1, u128::MAX - 1));
+    }
+}
@@ -0,0 +1,3 @@
+fn main() {
+    aoc::run_both::<day06::Day06a, day06::Day06b>();
+}
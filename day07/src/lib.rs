@@ -0,0 +1,376 @@
+use std::cmp::Reverse;
+use std::fmt;
+
+use aoc::{ParseError, Part, Solution};
+
+// every card that can appear in a hand
+pub const DECK: &str = "23456789TJQKA";
+
+#[derive(Debug, PartialEq, Clone, Copy, Eq, PartialOrd, Ord, Hash)]
+pub enum Combo {
+    HighCard,
+    OnePair,
+    TwoPair,
+    ThreeOfAKind,
+    FullHouse,
+    FourOfAKind,
+    FiveOfAKind,
+}
+
+impl fmt::Display for Combo {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            Combo::HighCard => "high card",
+            Combo::OnePair => "one pair",
+            Combo::TwoPair => "two pair",
+            Combo::ThreeOfAKind => "three of a kind",
+            Combo::FullHouse => "full house",
+            Combo::FourOfAKind => "four of a kind",
+            Combo::FiveOfAKind => "five of a kind",
+        };
+        f.pad(name)
+    }
+}
+
+// each hand type with the card counts that make it, largest first
+pub const TABLE: &[(Combo, &[usize])] = &[
+    (Combo::HighCard, &[1, 1, 1, 1, 1]),
+    (Combo::OnePair, &[2, 1, 1, 1]),
+    (Combo::TwoPair, &[2, 2, 1]),
+    (Combo::ThreeOfAKind, &[3, 1, 1]),
+    (Combo::FullHouse, &[3, 2]),
+    (Combo::FourOfAKind, &[4, 1]),
+    (Combo::FiveOfAKind, &[5]),
+];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Hand {
+    pub cards: Vec<char>,
+    pub bid: u64,
+}
+
+// how hands are compared: first by the position of their type in the table, then
+// card by card by the position of each card in the order
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rules<'a> {
+    // weakest first; a card not listed ranks below all others
+    pub order: &'a str,
+    // cards that count as whichever card makes the hand strongest
+    pub wild: &'a str,
+    // weakest first; a hand whose counts are not listed ranks below all others
+    pub table: &'a [(Combo, &'a [usize])],
+}
+
+impl Rules<'_> {
+    pub const PART_ONE: Rules<'static> = Rules {
+        order: "23456789TJQKA",
+        wild: "",
+        table: TABLE,
+    };
+    // J is a joker: the weakest card on its own, but wild
+    pub const PART_TWO: Rules<'static> = Rules {
+        order: "J23456789TQKA",
+        wild: "J",
+        table: TABLE,
+    };
+
+    pub fn strength(&self, card: char) -> usize {
+        self.order.find(card).map_or(0, |i| i + 1)
+    }
+
+    // the card the wild cards join: the most common other card, the strongest
+    // of those on a tie; None if there is no wild card or no other card
+    pub fn promoted(&self, cards: &[char]) -> Option<char> {
+        if !cards.iter().any(|&c| self.wild.contains(c)) {
+            return None;
+        }
+        cards
+            .iter()
+            .filter(|&&c| !self.wild.contains(c))
+            .max_by_key(|&&c| (cards.iter().filter(|&&d| d == c).count(), self.strength(c)))
+            .copied()
+    }
+
+    // how many of each card the hand holds once the wild cards have joined the
+    // promoted card, largest first; a hand of only wild cards, of however many
+    // wild ranks, is one group
+    pub fn counts(&self, cards: &[char]) -> Vec<usize> {
+        let mut seen = Vec::new();
+        let mut counts = Vec::new();
+        let mut wild = 0;
+        for &c in cards {
+            if self.wild.contains(c) {
+                wild += 1;
+            } else if !seen.contains(&c) {
+                seen.push(c);
+                counts.push(cards.iter().filter(|&&d| d == c).count());
+            }
+        }
+        counts.sort_by_key(|&n| Reverse(n));
+        match counts.first_mut() {
+            Some(first) => *first += wild,
+            None if wild > 0 => counts.push(wild),
+            None => {}
+        }
+        counts
+    }
+
+    // the type of the hand and its position in the table, counting from 1
+    pub fn combo(&self, cards: &[char]) -> Option<(usize, Combo)> {
+        let counts = self.counts(cards);
+        self.table
+            .iter()
+            .position(|(_, shape)| *shape == counts)
+            .map(|i| (i + 1, self.table[i].0))
+    }
+
+    fn key(&self, hand: &Hand) -> (usize, Vec<usize>) {
+        let combo = self.combo(&hand.cards).map_or(0, |(i, _)| i);
+        (
+            combo,
+            hand.cards.iter().map(|&c| self.strength(c)).collect(),
+        )
+    }
+
+    // weakest first; hands that compare equal keep their input order
+    pub fn ranking<'h>(&self, hands: &'h [Hand]) -> Vec<&'h Hand> {
+        let mut ranked: Vec<&Hand> = hands.iter().collect();
+        ranked.sort_by_cached_key(|h| self.key(h));
+        ranked
+    }
+
+    // each bid times the rank of its hand, weakest first from 1
+    pub fn winnings(&self, hands: &[Hand]) -> u128 {
+        self.ranking(hands)
+            .iter()
+            .enumerate()
+            .map(|(i, h)| (i as u128 + 1) * h.bid as u128)
+            .sum()
+    }
+}
+
+// how one hand was classified and ranked
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Row {
+    // in the ranking, weakest first from 1
+    pub rank: usize,
+    pub cards: String,
+    pub bid: u64,
+    pub combo: Option<Combo>,
+    // what the wild cards counted as
+    pub promoted: Option<char>,
+    // after the wild cards joined in, largest first
+    pub counts: Vec<usize>,
+    pub winnings: u128,
+}
+
+impl Rules<'_> {
+    // one row per hand, in ranking order
+    pub fn rows(&self, hands: &[Hand]) -> Vec<Row> {
+        self.ranking(hands)
+            .into_iter()
+            .enumerate()
+            .map(|(i, h)| Row {
+                rank: i + 1,
+                cards: h.cards.iter().collect(),
+                bid: h.bid,
+                combo: self.combo(&h.cards).map(|(_, c)| c),
+                promoted: self.promoted(&h.cards),
+                counts: self.counts(&h.cards),
+                winnings: (i as u128 + 1) * h.bid as u128,
+            })
+            .collect()
+    }
+}
+
+impl Row {
+    fn fields(&self) -> [String; 7] {
+        let counts: Vec<String> = self.counts.iter().map(|n| n.to_string()).collect();
+        [
+            self.rank.to_string(),
+            self.cards.clone(),
+            self.bid.to_string(),
+            self.combo.map_or("unlisted".to_string(), |c| c.to_string()),
+            self.promoted.map_or("-".to_string(), |c| c.to_string()),
+            counts.join(" "),
+            self.winnings.to_string(),
+        ]
+    }
+}
+
+const COLUMNS: [&str; 7] = [
+    "rank", "cards", "bid", "type", "wild as", "counts", "winnings",
+];
+
+// for each part, aligned columns under a header with the total last
+pub fn text_report(parts: &[(Part, Vec<Row>)]) -> String {
+    let line = |f: [&str; 7]| {
+        format!(
+            "{:>5}  {:5}  {:>6}  {:15}  {:7}  {:9}  {:>10}\n",
+            f[0], f[1], f[2], f[3], f[4], f[5], f[6]
+        )
+    };
+    let mut out = String::new();
+    for (part, rows) in parts {
+        out += &format!("part {}\n", part);
+        out += &line(COLUMNS);
+        for row in rows {
+            out += &line(row.fields().each_ref().map(|f| f.as_str()));
+        }
+        let total: u128 = rows.iter().map(|r| r.winnings).sum();
+        out += &format!("{:>70}\n", total);
+    }
+    out
+}
+
+// one line per hand and part under a header; no field needs quoting
+pub fn csv_report(parts: &[(Part, Vec<Row>)]) -> String {
+    let mut out = "part,".to_string() + &COLUMNS.map(|c| c.replace(' ', "_")).join(",") + "\n";
+    for (part, rows) in parts {
+        for row in rows {
+            out += &format!("{},{}\n", part, row.fields().join(","));
+        }
+    }
+    out
+}
+
+// "32T3K 765"
+pub fn hands(input: &str) -> Result<Vec<Hand>, ParseError> {
+    aoc::lines(input)
+        .map(|l| {
+            let (cards, bid) = l.trim().split_once(" ")?;
+            let cards = cards.cells("a card: 2-9, T, J, Q, K or A", |c| {
+                DECK.contains(c).then_some(c)
+            })?;
+            if cards.len() != 5 {
+                return Err(l.trim().error("a hand of five cards"));
+            }
+            Ok(Hand {
+                cards,
+                bid: bid.parse("a bid")?,
+            })
+        })
+        .collect()
+}
+
+pub struct Day07a;
+
+impl Solution for Day07a {
+    const DAY: u8 = 7;
+    const PART: Part = Part::One;
+    type Input = Vec<Hand>;
+    type Answer = u128;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        hands(input)
+    }
+
+    fn solve(hands: &Self::Input) -> u128 {
+        Rules::PART_ONE.winnings(hands)
+    }
+}
+
+pub struct Day07b;
+
+impl Solution for Day07b {
+    const DAY: u8 = 7;
+    const PART: Part = Part::Two;
+    type Input = Vec<Hand>;
+    type Answer = u128;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        hands(input)
+    }
+
+    fn solve(hands: &Self::Input) -> u128 {
+        Rules::PART_TWO.winnings(hands)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+32T3K 765
+T55J5 684
+KK677 28
+KTJJT 220
+QQQJA 483
+";
+
+    #[test]
+    fn example() {
+        assert_eq!(aoc::solve::<Day07a>(EXAMPLE), Ok(6440));
+        assert_eq!(aoc::solve::<Day07b>(EXAMPLE), Ok(5905));
+    }
+
+    #[test]
+    fn bad_card() {
+        let e = aoc::solve::<Day07a>("32T3K 765\r\nT55X5 684\r\n").unwrap_err();
+        assert_eq!((e.day, e.line, e.column), (7, 2, 4));
+        let e = aoc::solve::<Day07a>("32T3 765\n").unwrap_err();
+        assert_eq!(e.expected, "a hand of five cards");
+    }
+
+    #[test]
+    fn reports() {
+        let hands = hands(EXAMPLE).unwrap();
+        let rows = Rules::PART_TWO.rows(&hands);
+        assert_eq!(
+            rows[4],
+            Row {
+                rank: 5,
+                cards: "KTJJT".to_string(),
+                bid: 220,
+                combo: Some(Combo::FourOfAKind),
+                promoted: Some('T'),
+                counts: vec![4, 1],
+                winnings: 1100,
+            }
+        );
+        let parts = [(Part::Two, rows)];
+        let csv = csv_report(&parts);
+        assert_eq!(
+            csv.lines().next(),
+            Some("part,rank,cards,bid,type,wild_as,counts,winnings")
+        );
+        assert_eq!(
+            csv.lines().nth(1),
+            Some("b,1,32T3K,765,one pair,-,2 1 1 1,765")
+        );
+        let text = text_report(&parts);
+        assert_eq!(text.lines().count(), 8);
+        assert!(text.ends_with(" 5905\n"));
+    }
+
+    #[test]
+    fn wild_cards() {
+        let cards: Vec<char> = "JJ2TJ".chars().collect();
+        assert_eq!(Rules::PART_TWO.combo(&cards), Some((6, Combo::FourOfAKind)));
+        assert_eq!(Rules::PART_TWO.promoted(&cards), Some('T'));
+        let all: Vec<char> = "JJJJJ".chars().collect();
+        assert_eq!(Rules::PART_TWO.counts(&all), vec![5]);
+        // twos and jokers both wild
+        let rules = Rules {
+            order: "2J3456789TQKA",
+            wild: "2J",
+            ..Rules::PART_TWO
+        };
+        let cards: Vec<char> = "2J3K4".chars().collect();
+        assert_eq!(rules.combo(&cards), Some((4, Combo::ThreeOfAKind)));
+        assert_eq!(rules.promoted(&cards), Some('K'));
+        let cards: Vec<char> = "22JJJ".chars().collect();
+        assert_eq!(rules.counts(&cards), vec![5]);
+        assert_eq!(rules.combo(&cards), Some((7, Combo::FiveOfAKind)));
+        assert_eq!(rules.promoted(&cards), None);
+        // without full houses in the table they rank below everything
+        let no_full_house = [&TABLE[..4], &TABLE[5..]].concat();
+        let rules = Rules {
+            table: &no_full_house,
+            ..Rules::PART_ONE
+        };
+        let hands = hands("KKK22 1\n23456 2\n").unwrap();
+        assert_eq!(rules.ranking(&hands)[0].bid, 1);
+    }
+}
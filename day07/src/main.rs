@@ -0,0 +1,21 @@
+use aoc::Part;
+use day07::{Day07a, Day07b, Rules};
+
+// with --report or --csv, how every hand was classified under both rules instead
+// of the answers
+fn main() {
+    let csv = aoc::csv_requested();
+    if !csv && !aoc::report_requested() {
+        return aoc::run_both::<Day07a, Day07b>();
+    }
+    aoc::print_report::<Day07a>(|hands| {
+        let parts = [
+            (Part::One, Rules::PART_ONE.rows(hands)),
+            (Part::Two, Rules::PART_TWO.rows(hands)),
+        ];
+        match csv {
+            true => day07::csv_report(&parts),
+            false => day07::text_report(&parts),
+        }
+    });
+}
@@ -0,0 +1,220 @@
+use std::collections::HashMap;
+use std::fmt;
+
+use aoc::{ParseError, Part, Solution};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Steps {
+    After(u128),
+    Never,
+    // the step is past what a u128 can count
+    TooLarge,
+}
+
+impl fmt::Display for Steps {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Steps::After(n) => n.fmt(f),
+            Steps::Never => f.pad("never"),
+            Steps::TooLarge => f.pad("too large"),
+        }
+    }
+}
+
+// how a walk ended
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Walk {
+    // the visitor asked to stop after this many steps
+    Stopped(u64),
+    // a (node, instruction index) state came round again: the walk repeats
+    // every cycle steps from pre_period on
+    Looped { pre_period: u64, cycle: u64 },
+}
+
+// the L/R instructions and the left/right successors of every node
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Network {
+    // 0 for L, 1 for R
+    moves: Vec<usize>,
+    names: Vec<String>,
+    index: HashMap<String, usize>,
+    next: Vec<[usize; 2]>,
+}
+
+impl Network {
+    // 0 for L, 1 for R
+    pub fn moves(&self) -> &[usize] {
+        &self.moves
+    }
+    pub fn names(&self) -> &[String] {
+        &self.names
+    }
+    pub fn node(&self, name: &str) -> Option<usize> {
+        self.index.get(name).copied()
+    }
+    // the left and right successors of a node
+    pub fn next(&self, node: usize) -> [usize; 2] {
+        self.next[node]
+    }
+
+    // follow the instructions from start, calling visit with each step number and
+    // node on the way, until it returns true or the walk is seen to loop; each
+    // (node, instruction index) state is visited once
+    pub fn walk(&self, start: usize, mut visit: impl FnMut(u64, usize) -> bool) -> Walk {
+        let period = self.moves.len();
+        let mut seen = vec![u64::MAX; self.names.len() * period];
+        let (mut node, mut step) = (start, 0u64);
+        loop {
+            let i = (step % period as u64) as usize;
+            let state = node * period + i;
+            if seen[state] != u64::MAX {
+                return Walk::Looped {
+                    pre_period: seen[state],
+                    cycle: step - seen[state],
+                };
+            }
+            seen[state] = step;
+            if visit(step, node) {
+                return Walk::Stopped(step);
+            }
+            node = self.next[node][self.moves[i]];
+            step += 1;
+        }
+    }
+
+    // the steps from the node called start to the first node that is an end,
+    // or Never if no end can be reached or there is no such start
+    pub fn steps(&self, start: &str, end: impl Fn(&str) -> bool) -> Steps {
+        let Some(start) = self.node(start) else {
+            return Steps::Never;
+        };
+        match self.walk(start, |_, node| end(&self.names[node])) {
+            Walk::Stopped(n) => Steps::After(n as u128),
+            Walk::Looped { .. } => Steps::Never,
+        }
+    }
+
+    // the nodes a predicate picks, e.g. every name ending in A
+    pub fn starts(&self, start: impl Fn(&str) -> bool) -> impl Iterator<Item = &str> {
+        self.names
+            .iter()
+            .map(|n| n.as_str())
+            .filter(move |n| start(n))
+    }
+}
+
+// "LLR", a blank line, then one "AAA = (BBB, CCC)" line per node
+pub fn network(input: &str) -> Result<Network, ParseError> {
+    let mut lines = aoc::lines(input);
+    let directions = lines.expect("a line of L/R instructions")?;
+    let moves = directions.cells("'L' or 'R'", |c| match c {
+        'L' => Some(0),
+        'R' => Some(1),
+        _ => None,
+    })?;
+    if moves.is_empty() {
+        return Err(directions.error_at_end("'L' or 'R'"));
+    }
+    let mut nodes = Vec::new();
+    for l in lines.filter(|l| !l.trim().is_empty()) {
+        let (src, dst) = l.split_once(" = ")?;
+        let (left, right) = dst.strip_prefix("(")?.strip_suffix(")")?.split_once(", ")?;
+        nodes.push((src, left, right));
+    }
+    let names: Vec<String> = nodes
+        .iter()
+        .map(|(n, _, _)| n.as_str().to_owned())
+        .collect();
+    let mut index = HashMap::new();
+    for (i, (src, _, _)) in nodes.iter().enumerate() {
+        if index.insert(src.as_str().to_owned(), i).is_some() {
+            return Err(src.error("a node not defined before"));
+        }
+    }
+    let mut next = Vec::new();
+    for (_, left, right) in &nodes {
+        let [l, r] = [left, right].map(|t| {
+            index
+                .get(t.as_str())
+                .copied()
+                .ok_or_else(|| t.error("a node defined in the network"))
+        });
+        next.push([l?, r?]);
+    }
+    Ok(Network {
+        moves,
+        names,
+        index,
+        next,
+    })
+}
+
+pub struct Day08a;
+
+impl Solution for Day08a {
+    const DAY: u8 = 8;
+    const PART: Part = Part::One;
+    type Input = Network;
+    type Answer = Steps;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        network(input)
+    }
+
+    fn solve(network: &Self::Input) -> Steps {
+        network.steps("AAA", |n| n == "ZZZ")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE_DIRECT: &str = "\
+RL
+
+AAA = (BBB, CCC)
+BBB = (DDD, EEE)
+CCC = (ZZZ, GGG)
+DDD = (DDD, DDD)
+EEE = (EEE, EEE)
+GGG = (GGG, GGG)
+ZZZ = (ZZZ, ZZZ)
+";
+
+    const EXAMPLE_REPEAT: &str = "\
+LLR
+
+AAA = (BBB, BBB)
+BBB = (AAA, ZZZ)
+ZZZ = (ZZZ, ZZZ)
+";
+
+    #[test]
+    fn example_direct() {
+        assert_eq!(aoc::solve::<Day08a>(EXAMPLE_DIRECT), Ok(Steps::After(2)));
+    }
+
+    #[test]
+    fn example_repeat() {
+        assert_eq!(aoc::solve::<Day08a>(EXAMPLE_REPEAT), Ok(Steps::After(6)));
+    }
+
+    #[test]
+    fn queries() {
+        let network = network(EXAMPLE_DIRECT).unwrap();
+        assert_eq!(network.steps("AAA", |n| n == "ZZZ"), Steps::After(2));
+        assert_eq!(network.steps("AAA", |n| n.ends_with('C')), Steps::After(1));
+        assert_eq!(network.steps("CCC", |n| n == "GGG"), Steps::After(1));
+        // under RL, AAA never passes B, and DDD only leads to itself
+        assert_eq!(network.steps("AAA", |n| n.ends_with('B')), Steps::Never);
+        assert_eq!(network.steps("DDD", |n| n == "ZZZ"), Steps::Never);
+        assert_eq!(network.steps("QQQ", |n| n == "ZZZ"), Steps::Never);
+        assert_eq!(
+            network.starts(|n| n.ends_with('Z')).collect::<Vec<_>>(),
+            vec!["ZZZ"]
+        );
+        let e = aoc::parse::<Day08a>("LR\n\nAAA = (BBB, ZZZ)\nZZZ = (ZZZ, ZZZ)\n").unwrap_err();
+        assert_eq!((e.line, e.column, e.found.as_str()), (3, 8, "\"BBB\""));
+    }
+}
@@ -0,0 +1,327 @@
+use std::collections::HashSet;
+
+use aoc::{Checked, ParseError, Part, Solution};
+use day08a::{network, Network, Steps, Walk};
+use petgraph::{
+    dot::{Config, Dot},
+    graphmap::DiGraphMap,
+};
+
+pub fn dot(network: &Network) -> String {
+    let names = network.names();
+    let g: DiGraphMap<&str, ()> = DiGraphMap::from_edges(
+        (0..names.len())
+            .flat_map(|i| {
+                network
+                    .next(i)
+                    .map(|j| (names[i].as_str(), names[j].as_str()))
+            })
+            .collect::<Vec<(&str, &str)>>(),
+    );
+    format!("{:?}", Dot::with_config(&g, &[Config::EdgeNoLabel]))
+}
+
+// where one ghost is on a Z node: the steps before its walk repeats, and the
+// steps within each cycle after that
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Ghost {
+    // steps before the first state that repeats
+    pub pre_period: u64,
+    pub cycle: u64,
+    // Z hits before the cycle, as steps from the start
+    pub early: Vec<u64>,
+    // Z hits in the first pass of the cycle, as steps from the start
+    pub in_cycle: Vec<u64>,
+}
+
+impl Ghost {
+    // follow one ghost over (node, instruction index) states until a state repeats
+    fn walk(network: &Network, start: usize, z: impl Fn(&str) -> bool) -> Ghost {
+        let mut hits = Vec::new();
+        let walk = network.walk(start, |step, node| {
+            if z(&network.names()[node]) {
+                hits.push(step);
+            }
+            false
+        });
+        let Walk::Looped { pre_period, cycle } = walk else {
+            unreachable!("the visitor never stops the walk")
+        };
+        let (early, in_cycle) = hits.iter().partition(|&&h| h < pre_period);
+        Ghost {
+            pre_period,
+            cycle,
+            early,
+            in_cycle,
+        }
+    }
+
+    fn on_z(&self, step: u64) -> bool {
+        match step < self.pre_period {
+            true => self.early.contains(&step),
+            false => {
+                let folded = self.pre_period + (step - self.pre_period) % self.cycle;
+                self.in_cycle.contains(&folded)
+            }
+        }
+    }
+}
+
+fn gcd(a: u128, b: u128) -> u128 {
+    match b {
+        0 => a,
+        _ => gcd(b, a % b),
+    }
+}
+
+// x with a * x = 1 mod m, for a and m coprime
+fn inverse(a: u128, m: u128) -> u128 {
+    let (mut r0, mut r1) = (a as i128 % m as i128, m as i128);
+    let (mut s0, mut s1) = (1i128, 0i128);
+    while r1 != 0 {
+        let q = r0 / r1;
+        (r0, r1) = (r1, r0 - q * r1);
+        (s0, s1) = (s1, s0 - q * s1);
+    }
+    s0.rem_euclid(m as i128) as u128
+}
+
+// a * b mod m for a and b below m, doubling so nothing passes m
+fn mul_mod(a: u128, mut b: u128, m: u128) -> u128 {
+    let add = |x: u128, y: u128| if x >= m - y { x - (m - y) } else { x + y };
+    let (mut a, mut product) = (a, 0);
+    while b > 0 {
+        if b & 1 == 1 {
+            product = add(product, a);
+        }
+        a = add(a, a);
+        b >>= 1;
+    }
+    product
+}
+
+// the x mod lcm(m1, m2) with x = r1 mod m1 and x = r2 mod m2: not found if there
+// is none, too large if the lcm does not fit in a u128; the moduli need not be
+// coprime
+fn crt((r1, m1): (u128, u128), (r2, m2): (u128, u128)) -> Checked<(u128, u128)> {
+    let g = gcd(m1, m2);
+    let diff = match r1 % m2 {
+        r if r <= r2 => r2 - r,
+        r => m2 - (r - r2),
+    };
+    if diff % g != 0 {
+        return Checked::NotFound;
+    }
+    let (a, m) = (m1 / g, m2 / g);
+    let k = mul_mod((diff / g) % m, inverse(a % m, m), m);
+    match m1.checked_mul(m) {
+        // r1 + m1 * k stays below m1 * m
+        Some(lcm) => Checked::Value(((r1 + m1 * k) % lcm, lcm)),
+        None => Checked::TooLarge,
+    }
+}
+
+// the first step at which every ghost is on a Z node; never without a ghost, and
+// too large once the combined cycles no longer fit in a u128
+pub fn meet(ghosts: &[Ghost]) -> Steps {
+    if ghosts.is_empty() {
+        return Steps::Never;
+    }
+    // until every ghost is in its cycle, try each step in turn
+    let settled = ghosts.iter().map(|g| g.pre_period).max().unwrap_or(0);
+    if let Some(step) = (0..settled).find(|&s| ghosts.iter().all(|g| g.on_z(s))) {
+        return Steps::After(step as u128);
+    }
+    // the usual shape: each ghost on a Z once per cycle, at a multiple of the cycle
+    let residues = |g: &Ghost| -> Vec<u128> {
+        let mut r: Vec<u128> = g.in_cycle.iter().map(|&h| (h % g.cycle) as u128).collect();
+        r.sort();
+        r.dedup();
+        r
+    };
+    let settled = settled as u128;
+    let first_from = |(r, m): (u128, u128)| match r >= settled {
+        true => Some(r),
+        false => (settled - r).div_ceil(m).checked_mul(m)?.checked_add(r),
+    };
+    if ghosts.iter().all(|g| residues(g) == [0]) {
+        let lcm = ghosts.iter().try_fold(1u128, |l, g| {
+            let c = g.cycle as u128;
+            (l / gcd(l, c)).checked_mul(c)
+        });
+        return match lcm.and_then(|l| first_from((0, l))) {
+            Some(step) => Steps::After(step),
+            None => Steps::TooLarge,
+        };
+    }
+    // otherwise every combination of residues, joined one ghost at a time; a
+    // combination past u128 might hide the first meeting, so it makes the answer
+    // too large
+    let mut joined = vec![(0u128, 1u128)];
+    for g in ghosts {
+        let mut next = HashSet::new();
+        for &a in &joined {
+            for r in residues(g) {
+                match crt(a, (r, g.cycle as u128)) {
+                    Checked::Value(j) => _ = next.insert(j),
+                    Checked::TooLarge => return Steps::TooLarge,
+                    Checked::NotFound => {}
+                }
+            }
+        }
+        joined = next.into_iter().collect();
+    }
+    let firsts: Option<Vec<u128>> = joined.into_iter().map(first_from).collect();
+    match firsts.map(|f| f.into_iter().min()) {
+        Some(Some(step)) => Steps::After(step),
+        Some(None) => Steps::Never,
+        None => Steps::TooLarge,
+    }
+}
+
+// every ghost starting on an A node
+pub fn ghosts(network: &Network) -> Vec<Ghost> {
+    network
+        .starts(|n| n.ends_with('A'))
+        .map(|n| Ghost::walk(network, network.node(n).unwrap(), |n| n.ends_with('Z')))
+        .collect()
+}
+
+pub struct Day08b;
+
+impl Solution for Day08b {
+    const DAY: u8 = 8;
+    const PART: Part = Part::Two;
+    type Input = Network;
+    type Answer = Steps;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        network(input)
+    }
+
+    fn solve(network: &Self::Input) -> Steps {
+        meet(&ghosts(network))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+LR
+
+11A = (11B, XXX)
+11B = (XXX, 11Z)
+11Z = (11B, XXX)
+22A = (22B, XXX)
+22B = (22C, 22C)
+22C = (22Z, 22Z)
+22Z = (22B, 22B)
+XXX = (XXX, XXX)
+";
+
+    // brute force: step every ghost until all stand on a Z, up to a limit
+    fn walk_all(network: &Network, limit: u128) -> Steps {
+        let names = network.names();
+        let mut at: Vec<usize> = (0..names.len())
+            .filter(|&i| names[i].ends_with('A'))
+            .collect();
+        for (step, &m) in (0..limit).zip(network.moves().iter().cycle()) {
+            if at.iter().all(|&i| names[i].ends_with('Z')) {
+                return Steps::After(step);
+            }
+            for i in at.iter_mut() {
+                *i = network.next(*i)[m];
+            }
+        }
+        Steps::Never
+    }
+
+    #[test]
+    fn example() {
+        assert_eq!(aoc::solve::<Day08b>(EXAMPLE), Ok(Steps::After(6)));
+        let ghosts = ghosts(&Day08b::parse(EXAMPLE).unwrap());
+        assert_eq!(ghosts[1].in_cycle, vec![3, 6]);
+        assert_eq!((ghosts[1].pre_period, ghosts[1].cycle), (1, 6));
+        assert!(ghosts[1].early.is_empty());
+    }
+
+    #[test]
+    fn irregular_ghosts() {
+        // the example with 22Z sending R to XXX: the 22 ghost is lost after its
+        // first Z, although the LCM of the first arrivals is still 6
+        let lost = EXAMPLE.replace("22Z = (22B, 22B)", "22Z = (22B, XXX)");
+        // ghosts on a Z away from a multiple of their cycle, a ghost only on a Z
+        // before its cycle, and a ghost that never meets the others
+        let offsets = "L\n\n11A = (11Z, 11Z)\n11Z = (11B, 11B)\n11B = (11C, 11C)\n\
+            11C = (11Z, 11Z)\n22A = (22B, 22B)\n22B = (22Z, 22Z)\n22Z = (22C, 22C)\n\
+            22C = (22D, 22D)\n22D = (22E, 22E)\n22E = (22Y, 22Y)\n22Y = (22Z, 22Z)\n";
+        let never = "L\n\n11A = (11Z, 11Z)\n11Z = (11B, 11B)\n11B = (11Z, 11Z)\n\
+            22A = (22Z, 22Z)\n22Z = (22Z, 22B)\n22B = (22B, 22B)\n\
+            33A = (33B, 33B)\n33B = (33Z, 33Z)\n33Z = (33B, 33B)\n";
+        let early = "L\n\n11A = (11Z, 11Z)\n11Z = (11B, 11B)\n11B = (11B, 11B)\n\
+            22A = (22Z, 22Z)\n22Z = (22Z, 22Z)\n";
+        for text in [&lost, offsets, early, never, EXAMPLE] {
+            let network = Day08b::parse(text).unwrap();
+            assert_eq!(
+                Day08b::solve(&network),
+                walk_all(&network, 10_000),
+                "{}",
+                text
+            );
+        }
+        let network = Day08b::parse(offsets).unwrap();
+        assert_eq!(Day08b::solve(&network), Steps::After(7));
+    }
+
+    // one ghost per cycle length, on a Z once per cycle, offset from the end of
+    // the cycle by the given number of steps
+    fn cycles(lengths: &[u64], offset: u64) -> String {
+        let mut text = "L\n\n".to_string();
+        for &p in lengths {
+            let name = |i| match i == p - 1 - offset {
+                true => format!("{}Z", p),
+                false => format!("{}c{}", p, i),
+            };
+            text += &format!("{0}A = ({1}, {1})\n", p, name(0));
+            for i in 0..p {
+                text += &format!("{0} = ({1}, {1})\n", name(i), name((i + 1) % p));
+            }
+        }
+        text
+    }
+
+    #[test]
+    fn past_u128() {
+        // the first 30 primes multiply to more than 2^128
+        let primes: Vec<u64> = (2..)
+            .filter(|&n: &u64| (2..n).all(|d| n % d != 0))
+            .take(30)
+            .collect();
+        for offset in [0, 1] {
+            let network = Day08b::parse(&cycles(&primes, offset)).unwrap();
+            assert_eq!(Day08b::solve(&network), Steps::TooLarge);
+            let network = Day08b::parse(&cycles(&primes[..5], offset)).unwrap();
+            assert_eq!(Day08b::solve(&network), walk_all(&network, 10_000));
+        }
+        assert_eq!(
+            crt((0, u128::MAX), (1, 2)),
+            Checked::TooLarge,
+            "u128::MAX is odd"
+        );
+        assert_eq!(mul_mod(u128::MAX - 1, u128::MAX - 1, u128::MAX), 1);
+    }
+
+    #[test]
+    fn no_ghosts() {
+        let network = Day08b::parse("L\n\nBBB = (ZZZ, ZZZ)\nZZZ = (BBB, BBB)\n").unwrap();
+        assert_eq!(Day08b::solve(&network), Steps::Never);
+    }
+
+    #[test]
+    fn undefined_node() {
+        let e = Day08b::parse("L\n\n11A = (11Z, 11B)\n11Z = (11A, 11A)\n").unwrap_err();
+        assert_eq!((e.line, e.column), (3, 13));
+    }
+}
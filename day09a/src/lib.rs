@@ -0,0 +1,219 @@
+use std::fmt;
+use std::str::FromStr;
+
+use aoc::{Checked, ParseError, Part, Solution};
+
+// the integer types a history can be read as; the work is done in i128 and every
+// value kept or returned must fit back into the type
+pub trait Value: Copy + fmt::Debug + fmt::Display + FromStr + Into<i128> + TryFrom<i128> {}
+
+impl<T: Copy + fmt::Debug + fmt::Display + FromStr + Into<i128> + TryFrom<i128>> Value for T {}
+
+fn fits<T: Value>(v: i128) -> Option<T> {
+    T::try_from(v).ok()
+}
+
+fn gcd(a: u128, b: u128) -> u128 {
+    match b {
+        0 => a,
+        _ => gcd(b, a % b),
+    }
+}
+
+// why a history has no polynomial
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Unfit {
+    // a difference does not fit in the value type
+    Overflow,
+    // the rows run out before one is all zeros
+    NotPolynomial,
+}
+
+impl fmt::Display for Unfit {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Unfit::Overflow => f.pad("the differences overflow"),
+            Unfit::NotPolynomial => f.pad("the differences never reach a row of zeros"),
+        }
+    }
+}
+
+// the polynomial through a history, from the first value of each row of its
+// difference table
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Polynomial<T> {
+    // how many values the history has
+    pub len: usize,
+    // f(0), then the first difference, the second and so on down to the last
+    // row that is not all zeros; empty if every value is zero
+    leading: Vec<T>,
+}
+
+impl<T: Value> Polynomial<T> {
+    pub fn new(history: &[T]) -> Result<Self, Unfit> {
+        let mut row: Vec<i128> = history.iter().map(|&v| v.into()).collect();
+        let mut leading = Vec::new();
+        while row.iter().any(|&v| v != 0) {
+            if row.len() == 1 {
+                return Err(Unfit::NotPolynomial);
+            }
+            leading.push(fits(row[0]).unwrap());
+            row = row
+                .windows(2)
+                .map(|w| w[1].checked_sub(w[0]).filter(|&d| fits::<T>(d).is_some()))
+                .collect::<Option<_>>()
+                .ok_or(Unfit::Overflow)?;
+        }
+        Ok(Polynomial {
+            len: history.len(),
+            leading,
+        })
+    }
+
+    // None for the zero polynomial
+    pub fn degree(&self) -> Option<usize> {
+        self.leading.len().checked_sub(1)
+    }
+
+    // the value at x, where the history holds x = 0 up to len - 1; None if it
+    // does not fit in the value type
+    pub fn at(&self, x: i128) -> Option<T> {
+        // the sum of C(x, j) times the first value of row j
+        let (mut value, mut c) = (0i128, 1i128);
+        for (j, &d) in self.leading.iter().enumerate() {
+            if j > 0 {
+                // C(x, j) = C(x, j - 1) * (x - j + 1) / j, divided first so
+                // only a result that is too big overflows
+                let j = j as i128;
+                let g = gcd(c.unsigned_abs(), j as u128) as i128;
+                c = (c / g).checked_mul(x.checked_sub(j - 1)? / (j / g))?;
+            }
+            value = value.checked_add(c.checked_mul(d.into())?)?;
+        }
+        fits(value)
+    }
+
+    // k steps after the last value, so 1 is the next one
+    pub fn ahead(&self, k: u64) -> Option<T> {
+        self.at(self.len as i128 - 1 + k as i128)
+    }
+
+    // k steps before the first value
+    pub fn behind(&self, k: u64) -> Option<T> {
+        self.at(-(k as i128))
+    }
+}
+
+// one history per line, each with its polynomial
+pub fn histories(input: &str) -> Result<Vec<Polynomial<i64>>, ParseError> {
+    aoc::lines(input)
+        .map(|l| {
+            let values: Vec<i64> = l.values("a number that fits in 64 bits")?;
+            Polynomial::new(&values).map_err(|e| match e {
+                Unfit::Overflow => l.error("differences that fit in 64 bits"),
+                Unfit::NotPolynomial => l.error("differences that reach a row of zeros"),
+            })
+        })
+        .collect()
+}
+
+// the sum of one prediction per history; too large if a prediction or the sum
+// falls outside i64 either way
+pub fn total(mut predictions: impl Iterator<Item = Option<i64>>) -> Checked<i64> {
+    predictions
+        .try_fold(0i64, |t, p| p.and_then(|p| t.checked_add(p)))
+        .into()
+}
+
+pub struct Day09a;
+
+impl Solution for Day09a {
+    const DAY: u8 = 9;
+    const PART: Part = Part::One;
+    type Input = Vec<Polynomial<i64>>;
+    type Answer = Checked<i64>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        histories(input)
+    }
+
+    fn solve(histories: &Self::Input) -> Checked<i64> {
+        total(histories.iter().map(|p| p.ahead(1)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+0 3 6 9 12 15
+1 3 6 10 15 21
+10 13 16 21 30 45
+";
+
+    #[test]
+    fn example() {
+        assert_eq!(aoc::solve::<Day09a>(EXAMPLE), Ok(Checked::Value(114)));
+        let histories = histories(EXAMPLE).unwrap();
+        let degrees: Vec<_> = histories.iter().map(|p| p.degree()).collect();
+        assert_eq!(degrees, vec![Some(1), Some(2), Some(3)]);
+        assert_eq!(histories[2].behind(1), Some(5));
+        assert_eq!(Polynomial::<i64>::new(&[0, 0]).unwrap().degree(), None);
+    }
+
+    #[test]
+    fn far_ahead_and_behind() {
+        let f = |x: i128| 3 * x * x * x - 2 * x + 7;
+        let history: Vec<i128> = (0..6).map(f).collect();
+        let p = Polynomial::new(&history).unwrap();
+        assert_eq!(p.degree(), Some(3));
+        for k in 1..100 {
+            assert_eq!(p.ahead(k), Some(f(5 + k as i128)), "{} ahead", k);
+            assert_eq!(p.behind(k), Some(f(-(k as i128))), "{} behind", k);
+        }
+        let k = 1u64 << 40;
+        assert_eq!(p.ahead(k), Some(f(5 + k as i128)));
+        // the same history read as i64 cannot hold that far
+        let history: Vec<i64> = history.iter().map(|&v| v as i64).collect();
+        let p = Polynomial::new(&history).unwrap();
+        assert_eq!(p.ahead(99), Some(f(104) as i64));
+        assert_eq!(p.ahead(k), None);
+    }
+
+    #[test]
+    fn unfit_histories() {
+        assert_eq!(
+            Polynomial::<i64>::new(&[1, 2, 4, 8, 16]),
+            Err(Unfit::NotPolynomial)
+        );
+        assert_eq!(Polynomial::<i64>::new(&[5]), Err(Unfit::NotPolynomial));
+        assert_eq!(
+            Polynomial::<i64>::new(&[i64::MIN, i64::MAX, i64::MAX, i64::MIN]),
+            Err(Unfit::Overflow)
+        );
+        assert_eq!(
+            Polynomial::<i128>::new(&[
+                i64::MIN.into(),
+                i64::MAX.into(),
+                i64::MAX.into(),
+                i64::MIN.into()
+            ])
+            .unwrap()
+            .degree(),
+            Some(2)
+        );
+        let e = aoc::solve::<Day09a>("1 2 3\n1 2 4 8 16\n").unwrap_err();
+        assert_eq!((e.line, e.column), (2, 1));
+        assert_eq!(e.expected, "differences that reach a row of zeros");
+        let big = format!("{} {} {}\n", i64::MAX - 2, i64::MAX - 1, i64::MAX);
+        assert_eq!(aoc::solve::<Day09a>(&big), Ok(Checked::TooLarge));
+        let sums = format!(
+            "{} {} {}\n1 1 1\n",
+            i64::MAX - 3,
+            i64::MAX - 2,
+            i64::MAX - 1
+        );
+        assert_eq!(aoc::solve::<Day09a>(&sums), Ok(Checked::TooLarge));
+    }
+}
@@ -0,0 +1,41 @@
+use aoc::{Checked, ParseError, Part, Solution};
+use day09a::{histories, total, Polynomial};
+
+pub struct Day09b;
+
+impl Solution for Day09b {
+    const DAY: u8 = 9;
+    const PART: Part = Part::Two;
+    type Input = Vec<Polynomial<i64>>;
+    type Answer = Checked<i64>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        histories(input)
+    }
+
+    fn solve(histories: &Self::Input) -> Checked<i64> {
+        total(histories.iter().map(|p| p.behind(1)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+0 3 6 9 12 15
+1 3 6 10 15 21
+10 13 16 21 30 45
+";
+
+    #[test]
+    fn example() {
+        assert_eq!(aoc::solve::<Day09b>(EXAMPLE), Ok(Checked::Value(2)));
+    }
+
+    #[test]
+    fn below_i64() {
+        let small = format!("{} {} {}\n", i64::MIN, i64::MIN + 1, i64::MIN + 2);
+        assert_eq!(aoc::solve::<Day09b>(&small), Ok(Checked::TooLarge));
+    }
+}
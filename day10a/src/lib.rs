@@ -0,0 +1,261 @@
+use aoc::{Line, ParseError, Part, Solution};
+use grid::{Dir, Grid, Pos};
+
+// each pipe with the two directions it opens to
+pub const PIPES: [(u8, [Dir; 2]); 6] = [
+    (b'|', [Dir::North, Dir::South]),
+    (b'-', [Dir::East, Dir::West]),
+    (b'L', [Dir::North, Dir::East]),
+    (b'J', [Dir::North, Dir::West]),
+    (b'7', [Dir::South, Dir::West]),
+    (b'F', [Dir::East, Dir::South]),
+];
+
+pub fn ends(c: u8) -> Option<[Dir; 2]> {
+    PIPES.iter().find(|&&(p, _)| p == c).map(|&(_, ends)| ends)
+}
+
+fn pipe(ends: [Dir; 2]) -> u8 {
+    let (p, _) = PIPES
+        .iter()
+        .find(|(_, e)| e.contains(&ends[0]) && e.contains(&ends[1]))
+        .unwrap();
+    *p
+}
+
+pub struct Maze {
+    // as read, S included
+    pub grid: Grid<u8>,
+    pub start: Pos,
+    // the pipe under S: the one joining the two ends the main loop uses
+    pub start_pipe: u8,
+    // every tile of the main loop in order, from S
+    pub main_loop: Vec<Pos>,
+}
+
+impl Maze {
+    // the tile at p, with S replaced by its pipe
+    pub fn tile(&self, p: Pos) -> u8 {
+        match p == self.start {
+            true => self.start_pipe,
+            false => self.grid[p],
+        }
+    }
+
+    // the tiles in the main loop
+    pub fn length(&self) -> usize {
+        self.main_loop.len()
+    }
+
+    // the steps along the loop to the tile furthest from S
+    pub fn farthest(&self) -> usize {
+        self.length() / 2
+    }
+
+    // the tiles inside the main loop, from its area by the shoelace formula
+    // and Pick's theorem: area = inside + loop / 2 - 1
+    pub fn enclosed(&self) -> usize {
+        let n = self.main_loop.len();
+        let twice_area: i64 = (0..n)
+            .map(|i| {
+                let (Pos(x1, y1), Pos(x2, y2)) = (self.main_loop[i], self.main_loop[(i + 1) % n]);
+                x1 as i64 * y2 as i64 - x2 as i64 * y1 as i64
+            })
+            .sum();
+        (twice_area.unsigned_abs() as usize + 2 - n) / 2
+    }
+
+    // the same by scanning each row: a tile is inside once the row has crossed
+    // the loop an odd number of times, counting the loop tiles that open north
+    pub fn enclosed_by_scanline(&self) -> usize {
+        let on_loop = self.on_loop();
+        let mut count = 0;
+        for y in 0..self.grid.height() {
+            let mut inside = false;
+            for x in 0..self.grid.width() {
+                let p = Pos(x as i32, y as i32);
+                match on_loop[p] {
+                    true => inside ^= ends(self.tile(p)).unwrap().contains(&Dir::North),
+                    false => count += inside as usize,
+                }
+            }
+        }
+        count
+    }
+
+    fn on_loop(&self) -> Grid<bool> {
+        let mut on_loop = self.grid.map(|_| false);
+        for &p in &self.main_loop {
+            on_loop[p] = true;
+        }
+        on_loop
+    }
+
+    // the maze with the loop blanked and every other tile marked I(nside) or O(utside)
+    pub fn render(&self) -> String {
+        let on_loop = self.on_loop();
+        let mut inside = false;
+        self.grid.render(|p, _| {
+            if p.0 == 0 {
+                inside = false;
+            }
+            match on_loop[p] {
+                true => {
+                    inside ^= ends(self.tile(p)).unwrap().contains(&Dir::North);
+                    ' '
+                }
+                false if inside => 'I',
+                false => 'O',
+            }
+        })
+    }
+}
+
+// the loop leaving S to the first side, if the pipes lead back to S
+fn walk(grid: &Grid<u8>, start: Pos, first: Dir) -> Option<(Vec<Pos>, Dir)> {
+    let mut tiles = vec![start];
+    let (mut p, mut heading) = (grid.step(start, first)?, first);
+    while p != start {
+        let [a, b] = ends(grid[p])?;
+        heading = match heading.invert() {
+            d if d == a => b,
+            d if d == b => a,
+            _ => return None,
+        };
+        tiles.push(p);
+        p = grid.step(p, heading)?;
+    }
+    Some((tiles, heading))
+}
+
+// one 'S' on a grid of pipes and '.', on a loop of pipes
+pub fn maze(input: &str) -> Result<Maze, ParseError> {
+    let lines: Vec<Line> = aoc::lines(input).collect();
+    let rows = aoc::rows(lines.iter().copied(), "a pipe, '.' or 'S'", |c| {
+        "|-LJ7F.S".contains(c).then_some(c as u8)
+    })?;
+    let grid = Grid::from_rows(rows);
+    let mut starts = lines.iter().enumerate().flat_map(|(y, l)| {
+        l.as_str()
+            .match_indices('S')
+            .map(move |(x, s)| (Pos(x as i32, y as i32), l.piece(s)))
+    });
+    let (start, s) = starts
+        .next()
+        .ok_or_else(|| lines.last().unwrap().error_at_end("an 'S'"))?;
+    if let Some((_, other)) = starts.next() {
+        return Err(other.error("only one 'S'"));
+    }
+    let (main_loop, start_ends) = Dir::ALL
+        .into_iter()
+        .find_map(|first| {
+            walk(&grid, start, first).map(|(tiles, last)| (tiles, [first, last.invert()]))
+        })
+        .ok_or_else(|| s.error("an 'S' on a loop of pipes"))?;
+    Ok(Maze {
+        start_pipe: pipe(start_ends),
+        grid,
+        start,
+        main_loop,
+    })
+}
+
+pub struct Day10a;
+
+impl Solution for Day10a {
+    const DAY: u8 = 10;
+    const PART: Part = Part::One;
+    type Input = Maze;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        maze(input)
+    }
+
+    fn solve(maze: &Self::Input) -> usize {
+        maze.farthest()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE_SQUARE: &str = "\
+.....
+.S-7.
+.|.|.
+.L-J.
+.....
+";
+
+    const EXAMPLE_COMPLEX: &str = "\
+..F7.
+.FJ|.
+SJ.L7
+|F--J
+LJ...
+";
+
+    #[test]
+    fn example_square() {
+        assert_eq!(aoc::solve::<Day10a>(EXAMPLE_SQUARE), Ok(4));
+    }
+
+    #[test]
+    fn example_complex() {
+        assert_eq!(aoc::solve::<Day10a>(EXAMPLE_COMPLEX), Ok(8));
+        let maze = maze(EXAMPLE_COMPLEX).unwrap();
+        assert_eq!((maze.start_pipe, maze.length()), (b'F', 16));
+    }
+
+    #[test]
+    fn start_pipe() {
+        // S in turn on each tile of a ring of pipes, with every neighbour outside
+        // the ring a dead end that opens towards S
+        let ring = [".....", ".F-7.", ".|.|.", ".L-J.", "....."];
+        for (x, y) in [
+            (1, 1),
+            (2, 1),
+            (3, 1),
+            (3, 2),
+            (3, 3),
+            (2, 3),
+            (1, 3),
+            (1, 2),
+        ] {
+            let mut rows: Vec<Vec<u8>> = ring.iter().map(|r| r.bytes().collect()).collect();
+            let pipe = rows[y][x];
+            rows[y][x] = b'S';
+            for (nx, ny, c) in [
+                (x, y - 1, b'|'),
+                (x, y + 1, b'|'),
+                (x - 1, y, b'-'),
+                (x + 1, y, b'-'),
+            ] {
+                if rows[ny][nx] == b'.' && (nx, ny) != (2, 2) {
+                    rows[ny][nx] = c;
+                }
+            }
+            let text: String = rows
+                .iter()
+                .map(|r| String::from_utf8(r.clone()).unwrap() + "\n")
+                .collect();
+            let maze = maze(&text).unwrap();
+            assert_eq!(maze.start_pipe, pipe, "{}", text);
+            assert_eq!((maze.length(), maze.farthest()), (8, 4), "{}", text);
+            assert_eq!(maze.enclosed(), 1, "{}", text);
+            assert_eq!(maze.enclosed_by_scanline(), 1, "{}", text);
+        }
+    }
+
+    #[test]
+    fn bad_mazes() {
+        let e = aoc::solve::<Day10a>("S-7\n|.|\nL-S\n").unwrap_err();
+        assert_eq!((e.line, e.column), (3, 3));
+        let e = aoc::solve::<Day10a>("F-7\n|.|\nL-J\n").unwrap_err();
+        assert_eq!(e.expected, "an 'S'");
+        let e = aoc::solve::<Day10a>("S-7\n|.|\nL-.\n").unwrap_err();
+        assert_eq!(e.expected, "an 'S' on a loop of pipes");
+    }
+}
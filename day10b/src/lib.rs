@@ -0,0 +1,94 @@
+use aoc::{ParseError, Part, Solution};
+use day10a::{maze, Maze};
+
+pub struct Day10b;
+
+impl Solution for Day10b {
+    const DAY: u8 = 10;
+    const PART: Part = Part::Two;
+    type Input = Maze;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        maze(input)
+    }
+
+    fn solve(maze: &Self::Input) -> usize {
+        let enclosed = maze.enclosed();
+        debug_assert_eq!(enclosed, maze.enclosed_by_scanline());
+        enclosed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE_ENCLOSED: &str = "\
+...........
+.S-------7.
+.|F-----7|.
+.||.....||.
+.||.....||.
+.|L-7.F-J|.
+.|..|.|..|.
+.L--J.L--J.
+...........
+";
+
+    const EXAMPLE_LARGER: &str = "\
+.F----7F7F7F7F-7....
+.|F--7||||||||FJ....
+.||.FJ||||||||L7....
+FJL7L7LJLJ||LJ.L-7..
+L--J.L7...LJS7F-7L7.
+....F-J..F7FJ|L7L7L7
+....L7.F7||L7|.L7L7|
+.....|FJLJ|FJ|F7|.LJ
+....FJL-7.||.||||...
+....L---J.LJ.LJLJ...
+";
+
+    const EXAMPLE_JUNK: &str = "\
+FF7FSF7F7F7F7F7F---7
+L|LJ||||||||||||F--J
+FL-7LJLJ||||||LJL-77
+F--JF--7||LJLJ7F7FJ-
+L---JF-JLJ.||-FJLJJ7
+|F|F-JF---7F7-L7L|7|
+|FFJF7L7F-JF7|JL---7
+7-L-JL7||F7|L7F-7F7|
+L.L7LFJ|||||FJL7||LJ
+L7JLJL-JLJLJL--JLJ.L
+";
+
+    #[test]
+    fn example_enclosed() {
+        assert_eq!(aoc::solve::<Day10b>(EXAMPLE_ENCLOSED), Ok(4));
+    }
+
+    #[test]
+    fn example_larger() {
+        assert_eq!(aoc::solve::<Day10b>(EXAMPLE_LARGER), Ok(8));
+    }
+
+    #[test]
+    fn example_junk() {
+        assert_eq!(aoc::solve::<Day10b>(EXAMPLE_JUNK), Ok(10));
+        let maze = maze(EXAMPLE_JUNK).unwrap();
+        assert_eq!(maze.enclosed_by_scanline(), 10);
+        // S is a 7, which the old scanline took for a crossing like |
+        assert_eq!(maze.start_pipe, b'7');
+    }
+
+    #[test]
+    fn start_under_the_scanline() {
+        // S stands for an L; taking it for a | or an F, as the old scanline did,
+        // leaves its row with an odd number of crossings
+        let text = "......\n.F--7.\n.|..|.\n.S--J.\n......\n";
+        let maze = maze(text).unwrap();
+        assert_eq!(maze.start_pipe, b'L');
+        assert_eq!(Day10b::solve(&maze), 2);
+        assert_eq!(maze.render(), "OOOOOO\nO    O\nO II O\nO    O\nOOOOOO\n");
+    }
+}
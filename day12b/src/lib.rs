@@ -0,0 +1,129 @@
+use std::collections::HashMap;
+
+use aoc::{ParseError, Part, Solution};
+use itertools::{repeat_n, Itertools};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Spring {
+    Damaged,
+    Operational,
+    Unknown,
+}
+
+impl TryFrom<char> for Spring {
+    type Error = char;
+    fn try_from(c: char) -> Result<Self, char> {
+        match c {
+            '.' => Ok(Self::Operational),
+            '#' => Ok(Self::Damaged),
+            '?' => Ok(Self::Unknown),
+            _ => Err(c),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Record {
+    row: Vec<Spring>,
+    damaged: Vec<usize>,
+    h: HashMap<(usize, usize, usize), usize>,
+}
+
+impl Record {
+    fn count(&mut self, rx: usize, dx: usize, cdmg: usize) -> usize {
+        let k = (rx, dx, cdmg);
+        if self.h.contains_key(&k) {
+            return *self.h.get(&k).unwrap();
+        }
+        if rx == self.row.len() {
+            return match (dx, cdmg) {
+                (x, 0) if x == self.damaged.len() => 1,
+                (x, y) if x == self.damaged.len() - 1 && y == self.damaged[x] => 1,
+                _ => 0,
+            };
+        }
+        let mut count = 0;
+        match self.row[rx] {
+            Spring::Damaged => {
+                count += self.count(rx + 1, dx, cdmg + 1);
+            }
+            Spring::Operational => {
+                if cdmg == 0 {
+                    count += self.count(rx + 1, dx, 0)
+                }
+                if dx < self.damaged.len() && self.damaged[dx] == cdmg {
+                    count += self.count(rx + 1, dx + 1, 0)
+                }
+            }
+            Spring::Unknown => {
+                count += self.count(rx + 1, dx, cdmg + 1); // assume Damaged
+                                                           // assume Operational
+                if cdmg == 0 {
+                    count += self.count(rx + 1, dx, 0)
+                }
+                if dx < self.damaged.len() && self.damaged[dx] == cdmg {
+                    count += self.count(rx + 1, dx + 1, 0)
+                }
+            }
+        }
+        self.h.insert(k, count);
+        count
+    }
+}
+
+pub struct Day12b;
+
+impl Solution for Day12b {
+    const DAY: u8 = 12;
+    const PART: Part = Part::Two;
+    type Input = Vec<Record>;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        aoc::lines(input)
+            .map(|l| {
+                let (row, damaged) = l.trim().split_once(" ")?;
+                let row = row.cells("'.', '#' or '?'", |c| Spring::try_from(c).ok())?;
+                let damaged: Vec<usize> = damaged
+                    .split(",")
+                    .map(|n| n.parse("a group size"))
+                    .collect::<Result<_, _>>()?;
+                Ok(Record {
+                    row: Itertools::intersperse(repeat_n(row, 5), vec![Spring::Unknown])
+                        .flatten()
+                        .collect(),
+                    damaged: repeat_n(damaged, 5).flatten().collect(),
+                    h: HashMap::new(),
+                })
+            })
+            .collect()
+    }
+
+    fn solve(records: &Self::Input) -> usize {
+        let mut records = records.clone();
+        // dbg!(records);
+        records
+            .iter_mut()
+            .map(|r: &mut Record| r.count(0, 0, 0))
+            .sum::<usize>()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+???.### 1,1,3
+.??..??...?##. 1,1,3
+?#?#?#?#?#?#?#? 1,3,1,6
+????.#...#... 4,1,1
+????.######..#####. 1,6,5
+?###???????? 3,2,1
+";
+
+    #[test]
+    fn example() {
+        assert_eq!(aoc::solve::<Day12b>(EXAMPLE), Ok(525152));
+    }
+}
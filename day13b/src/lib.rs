@@ -0,0 +1,112 @@
+use aoc::{ParseError, Part, Solution};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Cell {
+    Ash,
+    Rock,
+}
+
+impl TryFrom<char> for Cell {
+    type Error = char;
+    fn try_from(c: char) -> Result<Self, char> {
+        match c {
+            '.' => Ok(Self::Ash),
+            '#' => Ok(Self::Rock),
+            _ => Err(c),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Pattern {
+    rows: Vec<Vec<Cell>>,
+    cols: Vec<Vec<Cell>>,
+}
+
+impl Pattern {
+    fn from_rows(rows: Vec<Vec<Cell>>) -> Pattern {
+        let cols = rows
+            .first()
+            .map(|c| {
+                (0..c.len())
+                    .map(|idx| rows.iter().map(|row| *row.get(idx).unwrap()).collect())
+                    .collect()
+            })
+            .unwrap();
+        Pattern { rows, cols }
+    }
+
+    fn mirror(i: usize, r: &[Vec<Cell>]) -> bool {
+        (0..i.min(r.len() - i))
+            .map(|idx| {
+                r[i - idx - 1]
+                    .iter()
+                    .zip(r[i + idx].iter())
+                    .filter(|(a, b)| a != b)
+                    .count()
+            })
+            .sum::<usize>()
+            == 1
+    }
+}
+
+pub struct Day13b;
+
+impl Solution for Day13b {
+    const DAY: u8 = 13;
+    const PART: Part = Part::Two;
+    type Input = Vec<Pattern>;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        aoc::blocks(input)
+            .into_iter()
+            .map(|block| {
+                let rows = aoc::rows(block, "'.' or '#'", |c| Cell::try_from(c).ok())?;
+                Ok(Pattern::from_rows(rows))
+            })
+            .collect()
+    }
+
+    fn solve(patterns: &Self::Input) -> usize {
+        patterns
+            .iter()
+            .map(|p| {
+                100 * (1..p.rows.len())
+                    .find(|&i| Pattern::mirror(i, &p.rows))
+                    .unwrap_or_default()
+                    + (1..p.cols.len())
+                        .find(|&i| Pattern::mirror(i, &p.cols))
+                        .unwrap_or_default()
+            })
+            .sum::<usize>()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+#.##..##.
+..#.##.#.
+##......#
+##......#
+..#.##.#.
+..##..##.
+#.#.##.#.
+
+#...##..#
+#....#..#
+..##..###
+#####.##.
+#####.##.
+..##..###
+#....#..#
+";
+
+    #[test]
+    fn example() {
+        assert_eq!(aoc::solve::<Day13b>(EXAMPLE), Ok(400));
+    }
+}
@@ -0,0 +1,157 @@
+use std::{
+    collections::HashSet,
+    fmt::{self, Debug},
+};
+
+use aoc::{ParseError, Part, Solution};
+use grid::{Bounds, Dir, Grid, Pos};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default)]
+enum Cell {
+    Round,
+    Cube,
+    #[default]
+    None,
+}
+
+impl TryFrom<char> for Cell {
+    type Error = char;
+    fn try_from(c: char) -> Result<Self, char> {
+        match c {
+            '.' => Ok(Self::None),
+            '#' => Ok(Self::Cube),
+            'O' => Ok(Self::Round),
+            _ => Err(c),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct Pattern {
+    bounds: Bounds,
+    round: Vec<Pos>,
+    cube: HashSet<Pos>,
+}
+
+impl Debug for Pattern {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f)?;
+        for y in 0..self.bounds.height {
+            for x in 0..self.bounds.width {
+                let p = Pos(x as i32, y as i32);
+                if self.cube.contains(&p) {
+                    write!(f, "#")?;
+                } else if self.round.contains(&p) {
+                    write!(f, "O")?;
+                } else {
+                    write!(f, ".")?;
+                }
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+impl Pattern {
+    fn from_grid(g: Grid<Cell>) -> Pattern {
+        let mut p = Pattern {
+            bounds: g.bounds(),
+            round: Vec::new(),
+            cube: HashSet::new(),
+        };
+        g.iter().for_each(|(pos, cell)| match cell {
+            Cell::Round => p.round.push(pos),
+            Cell::Cube => {
+                p.cube.insert(pos);
+            }
+            _ => {}
+        });
+        p
+    }
+    fn roll(&self, p: Pos, d: Dir) -> Option<Pos> {
+        Some(p.step(d)).filter(|&n| self.bounds.contains(n))
+    }
+    fn order(&mut self, d: Dir) {
+        self.round.sort_by(|a, b| match d {
+            Dir::East => b.0.cmp(&a.0),
+            Dir::West => a.0.cmp(&b.0),
+            Dir::North => b.1.cmp(&a.1),
+            Dir::South => a.1.cmp(&b.1),
+        })
+    }
+    fn tilt(&mut self, d: Dir) {
+        self.order(d);
+        let count = match d {
+            Dir::East | Dir::West => self.bounds.width,
+            Dir::North | Dir::South => self.bounds.height,
+        };
+        for _ in 0..count {
+            self.round = self
+                .round
+                .iter()
+                .map(|&p| match self.roll(p, d) {
+                    Some(nxt) => {
+                        if self.cube.contains(&nxt) || self.round.contains(&nxt) {
+                            p
+                        } else {
+                            nxt
+                        }
+                    }
+                    None => p,
+                })
+                .collect();
+        }
+    }
+    fn score(&self) -> usize {
+        self.round
+            .iter()
+            .map(|Pos(_, y)| self.bounds.height as i32 - y)
+            .sum::<i32>() as usize
+    }
+}
+
+pub struct Day14a;
+
+impl Solution for Day14a {
+    const DAY: u8 = 14;
+    const PART: Part = Part::One;
+    type Input = Pattern;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let rows = aoc::rows(aoc::lines(input), "'.', 'O' or '#'", |c| {
+            Cell::try_from(c).ok()
+        })?;
+        Ok(Pattern::from_grid(Grid::from_rows(rows)))
+    }
+
+    fn solve(pat: &Self::Input) -> usize {
+        let mut pat = pat.clone();
+        pat.tilt(Dir::North);
+        pat.score()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+O....#....
+O.OO#....#
+.....##...
+OO.#O....O
+.O.....O#.
+O.#..O.#.#
+..O..#O..O
+.......O..
+#....###..
+#OO..#....
+";
+
+    #[test]
+    fn example() {
+        assert_eq!(aoc::solve::<Day14a>(EXAMPLE), Ok(136));
+    }
+}
@@ -0,0 +1,169 @@
+use std::{
+    collections::{BTreeSet, HashMap, HashSet},
+    fmt::{self, Debug},
+};
+
+use aoc::{ParseError, Part, Solution};
+use grid::{Bounds, Dir, Grid, Pos};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default)]
+enum Cell {
+    Round,
+    Cube,
+    #[default]
+    None,
+}
+
+impl TryFrom<char> for Cell {
+    type Error = char;
+    fn try_from(c: char) -> Result<Self, char> {
+        match c {
+            '.' => Ok(Self::None),
+            '#' => Ok(Self::Cube),
+            'O' => Ok(Self::Round),
+            _ => Err(c),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct Pattern {
+    bounds: Bounds,
+    round: HashSet<Pos>,
+    cube: HashSet<Pos>,
+}
+
+impl Debug for Pattern {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f)?;
+        for y in 0..self.bounds.height {
+            for x in 0..self.bounds.width {
+                let p = Pos(x as i32, y as i32);
+                if self.cube.contains(&p) {
+                    write!(f, "#")?;
+                } else if self.round.contains(&p) {
+                    write!(f, "O")?;
+                } else {
+                    write!(f, ".")?;
+                }
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+impl Pattern {
+    fn from_grid(g: Grid<Cell>) -> Pattern {
+        let mut p = Pattern {
+            bounds: g.bounds(),
+            round: HashSet::new(),
+            cube: HashSet::new(),
+        };
+        g.iter().for_each(|(pos, cell)| match cell {
+            Cell::Round => {
+                p.round.insert(pos);
+            }
+            Cell::Cube => {
+                p.cube.insert(pos);
+            }
+            _ => {}
+        });
+        p
+    }
+    fn roll(&self, p: Pos, d: Dir) -> Option<Pos> {
+        Some(p.step(d)).filter(|&n| self.bounds.contains(n))
+    }
+    fn tilt(&mut self, d: Dir) {
+        let count = match d {
+            Dir::East | Dir::West => self.bounds.width,
+            Dir::North | Dir::South => self.bounds.height,
+        };
+        for _ in 0..count {
+            let next = self
+                .round
+                .iter()
+                .map(|&p| match self.roll(p, d) {
+                    Some(nxt) => {
+                        if self.cube.contains(&nxt) || self.round.contains(&nxt) {
+                            p
+                        } else {
+                            nxt
+                        }
+                    }
+                    None => p,
+                })
+                .collect();
+            if next == self.round {
+                break;
+            }
+            self.round = next;
+        }
+    }
+    fn score(&self) -> usize {
+        self.round
+            .iter()
+            .map(|&Pos(_, y)| self.bounds.height as i32 - y)
+            .sum::<i32>() as usize
+    }
+}
+
+pub struct Day14b;
+
+impl Solution for Day14b {
+    const DAY: u8 = 14;
+    const PART: Part = Part::Two;
+    type Input = Pattern;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let rows = aoc::rows(aoc::lines(input), "'.', 'O' or '#'", |c| {
+            Cell::try_from(c).ok()
+        })?;
+        Ok(Pattern::from_grid(Grid::from_rows(rows)))
+    }
+
+    fn solve(pat: &Self::Input) -> usize {
+        let mut pat = pat.clone();
+        let mut seen = HashMap::new();
+        let mut scores = Vec::new();
+        for i in 0..300 {
+            pat.tilt(Dir::North);
+            pat.tilt(Dir::West);
+            pat.tilt(Dir::South);
+            pat.tilt(Dir::East);
+            let score = pat.score();
+            scores.push(score);
+            let hash = pat.round.iter().cloned().collect::<BTreeSet<_>>();
+            if seen.contains_key(&hash) {
+                let idx = seen.get(&hash).unwrap();
+                return scores[idx - 1 + (1000000000 - idx) % (seen.len() - idx)];
+            }
+            seen.insert(hash, i);
+        }
+        panic!("no cycle within 300 spins")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+O....#....
+O.OO#....#
+.....##...
+OO.#O....O
+.O.....O#.
+O.#..O.#.#
+..O..#O..O
+.......O..
+#....###..
+#OO..#....
+";
+
+    #[test]
+    fn example() {
+        assert_eq!(aoc::solve::<Day14b>(EXAMPLE), Ok(64));
+    }
+}
@@ -0,0 +1,38 @@
+use aoc::{ParseError, Part, Solution};
+
+pub struct Day15a;
+
+impl Solution for Day15a {
+    const DAY: u8 = 15;
+    const PART: Part = Part::One;
+    type Input = Vec<String>;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(aoc::lines(input)
+            .flat_map(|l| l.split(","))
+            .map(|step| step.as_str().to_owned())
+            .collect())
+    }
+
+    fn solve(steps: &Self::Input) -> usize {
+        steps
+            .iter()
+            .map(|l| l.chars().fold(0, |h, c| ((h + c as usize) * 17) % 256))
+            .sum::<usize>()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7
+";
+
+    #[test]
+    fn example() {
+        assert_eq!(aoc::solve::<Day15a>(EXAMPLE), Ok(1320));
+    }
+}
@@ -0,0 +1,72 @@
+use aoc::{ParseError, Part, Solution};
+
+pub struct Day15b;
+
+impl Solution for Day15b {
+    const DAY: u8 = 15;
+    const PART: Part = Part::Two;
+    type Input = Vec<String>;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        aoc::lines(input)
+            .flat_map(|l| l.split(","))
+            .map(|step| {
+                if step.as_str().strip_suffix('-').is_none() {
+                    step.split_once("=")?.1.parse::<usize>("a focal length")?;
+                }
+                Ok(step.as_str().to_owned())
+            })
+            .collect()
+    }
+
+    fn solve(steps: &Self::Input) -> usize {
+        let mut lenses: Vec<Vec<(String, usize)>> = vec![Vec::new(); 256];
+        steps.iter().for_each(|l| {
+            let parts = l.split_once(['=', '-']);
+            match parts {
+                Some((label, "")) => {
+                    let h = label.chars().fold(0, |h, c| ((h + c as usize) * 17) % 256);
+                    lenses[h].retain(|l| l.0 != label)
+                }
+                Some((label, lens)) => {
+                    let h = label.chars().fold(0, |h, c| ((h + c as usize) * 17) % 256);
+                    let lens = lens.parse().unwrap();
+                    lenses[h]
+                        .iter_mut()
+                        .find(|l| l.0 == label)
+                        .map(|l| l.1 = lens)
+                        .or_else(|| {
+                            lenses[h].push((label.to_owned(), lens));
+                            Some(())
+                        });
+                }
+                _ => unreachable!(),
+            }
+        });
+        lenses
+            .iter()
+            .enumerate()
+            .map(|(bidx, b)| {
+                b.iter()
+                    .enumerate()
+                    .map(|(lidx, (_, l))| l * (1 + bidx) * (1 + lidx))
+                    .sum::<usize>()
+            })
+            .sum::<usize>()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7
+";
+
+    #[test]
+    fn example() {
+        assert_eq!(aoc::solve::<Day15b>(EXAMPLE), Ok(145));
+    }
+}
@@ -0,0 +1,205 @@
+use std::{
+    collections::{HashSet, VecDeque},
+    fmt::{self, Debug},
+};
+
+use aoc::{ParseError, Part, Solution};
+use grid::{Dir, Grid, Pos};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default)]
+enum Cell {
+    MirrorRight,
+    MirrorLeft,
+    SplitterVertical,
+    SplitterHorizontal,
+    #[default]
+    None,
+}
+
+impl Cell {
+    fn is_mirror(&self) -> bool {
+        matches!(self, Self::MirrorLeft | Self::MirrorRight)
+    }
+    fn is_splitter(&self) -> bool {
+        matches!(self, Self::SplitterHorizontal | Self::SplitterVertical)
+    }
+}
+
+impl TryFrom<char> for Cell {
+    type Error = char;
+    fn try_from(c: char) -> Result<Self, char> {
+        match c {
+            '.' => Ok(Self::None),
+            '|' => Ok(Self::SplitterVertical),
+            '-' => Ok(Self::SplitterHorizontal),
+            '\\' => Ok(Self::MirrorRight),
+            '/' => Ok(Self::MirrorLeft),
+            _ => Err(c),
+        }
+    }
+}
+
+impl From<Cell> for char {
+    fn from(c: Cell) -> char {
+        match c {
+            Cell::None => '.',
+            Cell::SplitterVertical => '|',
+            Cell::SplitterHorizontal => '-',
+            Cell::MirrorRight => '\\',
+            Cell::MirrorLeft => '/',
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct Pattern {
+    grid: Grid<Cell>,
+}
+
+impl Debug for Pattern {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f)?;
+        let text = self.grid.render(|_, &c| char::from(c));
+        write!(f, "{}", text)
+    }
+}
+
+impl Pattern {
+    fn at(&self, p: Pos) -> Cell {
+        self.grid[p]
+    }
+    fn get(&self, p: Pos, d: Dir) -> Option<Pos> {
+        self.grid.step(p, d)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+struct Beam {
+    pos: Pos,
+    dir: Dir,
+}
+
+impl Beam {
+    fn reflect(&mut self, c: Cell) {
+        assert!(c.is_mirror());
+        self.dir = match c {
+            Cell::MirrorRight => match self.dir {
+                Dir::North => Dir::West,
+                Dir::South => Dir::East,
+                Dir::East => Dir::South,
+                Dir::West => Dir::North,
+            },
+            Cell::MirrorLeft => match self.dir {
+                Dir::North => Dir::East,
+                Dir::South => Dir::West,
+                Dir::East => Dir::North,
+                Dir::West => Dir::South,
+            },
+            _ => self.dir,
+        }
+    }
+    fn split(&mut self, c: Cell) -> Option<Beam> {
+        assert!(c.is_splitter());
+        match c {
+            Cell::SplitterVertical => match self.dir {
+                Dir::North | Dir::South => None,
+                Dir::East | Dir::West => {
+                    self.dir = Dir::North;
+                    Some(Beam {
+                        pos: self.pos,
+                        dir: Dir::South,
+                    })
+                }
+            },
+            Cell::SplitterHorizontal => match self.dir {
+                Dir::North | Dir::South => {
+                    self.dir = Dir::East;
+                    Some(Beam {
+                        pos: self.pos,
+                        dir: Dir::West,
+                    })
+                }
+                Dir::East | Dir::West => None,
+            },
+            _ => None,
+        }
+    }
+}
+
+pub struct Day16a;
+
+impl Solution for Day16a {
+    const DAY: u8 = 16;
+    const PART: Part = Part::One;
+    type Input = Pattern;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let rows = aoc::rows(aoc::lines(input), "'.', '|', '-', '\\' or '/'", |c| {
+            Cell::try_from(c).ok()
+        })?;
+        Ok(Pattern {
+            grid: Grid::from_rows(rows),
+        })
+    }
+
+    fn solve(pat: &Self::Input) -> usize {
+        let mut visited: HashSet<Beam> = HashSet::new();
+        let beam = Beam {
+            pos: Pos(0, 0),
+            dir: Dir::East,
+        };
+        let mut q: VecDeque<Beam> = VecDeque::new();
+        q.push_back(beam);
+        while let Some(mut curr) = q.pop_front() {
+            let cp = curr.pos;
+            let cc = pat.at(cp);
+            visited.insert(curr);
+            if cc.is_mirror() {
+                curr.reflect(cc)
+            } else if cc.is_splitter() {
+                if let Some(next) = curr.split(cc) {
+                    if !visited.contains(&next) {
+                        q.push_back(next);
+                    }
+                }
+            }
+            if let Some(np) = pat.get(curr.pos, curr.dir) {
+                let neigh = Beam {
+                    pos: np,
+                    dir: curr.dir,
+                };
+                if !visited.contains(&neigh) {
+                    q.push_back(neigh);
+                }
+            }
+        }
+        visited
+            .iter()
+            .map(|x| x.pos)
+            .collect::<HashSet<Pos>>()
+            .len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = r".|...\....
+|.-.\.....
+.....|-...
+........|.
+..........
+.........\
+..../.\\..
+.-.-/..|..
+.|....-|.\
+..//.|....
+";
+
+    #[test]
+    fn example() {
+        assert_eq!(aoc::solve::<Day16a>(EXAMPLE), Ok(46));
+    }
+}
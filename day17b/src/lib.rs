@@ -0,0 +1,115 @@
+use std::collections::HashMap;
+
+use aoc::{ParseError, Part, Solution};
+use pathfinding::{
+    directed::dijkstra::dijkstra,
+    matrix::{directions, Matrix},
+};
+
+pub struct Day17b;
+
+impl Solution for Day17b {
+    const DAY: u8 = 17;
+    const PART: Part = Part::Two;
+    type Input = Matrix<u8>;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let rows = aoc::rows(aoc::lines(input), "a digit", |c| {
+            c.to_digit(10).map(|d| d as u8)
+        })?;
+        Ok(rows.into_iter().collect::<Matrix<u8>>())
+    }
+
+    fn solve(grid: &Self::Input) -> usize {
+        solve_and_render(grid).0
+    }
+}
+
+// the least heat loss and the city map with the crucible path drawn on it
+pub fn solve_and_render(grid: &Matrix<u8>) -> (usize, String) {
+    let d = dijkstra(
+        &((0, 0), (0, 0), 4), // free to set off east or south
+        |&(pos, cdir, cdist)| {
+            let mut neigh = Vec::new();
+            if cdist >= 4 {
+                for d in [directions::N, directions::S, directions::E, directions::W] {
+                    if let Some(np) = grid.move_in_direction(pos, d) {
+                        let wt = *grid.get(np).unwrap() as usize;
+                        if d != (-cdir.0, -cdir.1) && d != cdir {
+                            neigh.push(((np, d, 1), wt));
+                        } else if cdist < 10 && d == cdir {
+                            neigh.push(((np, d, cdist + 1), wt));
+                        }
+                    }
+                }
+            } else {
+                if let Some(np) = grid.move_in_direction(pos, cdir) {
+                    let wt = *grid.get(np).unwrap() as usize;
+                    neigh.push(((np, cdir, cdist + 1), wt));
+                }
+            }
+            neigh
+        },
+        |&(pos, _, dist)| pos == (grid.rows - 1, grid.columns - 1) && dist >= 4,
+    );
+    let (path, dist) = d.unwrap();
+    // println!("{:?}", path);
+    let mut vis: HashMap<(usize, usize), (isize, isize)> = HashMap::new();
+    for (pos, d, _) in path {
+        vis.insert(pos, d);
+    }
+    let mut render = String::new();
+    for i in 0..grid.rows {
+        for j in 0..grid.columns {
+            match vis.get(&(i, j)) {
+                Some(&directions::N) => render.push('^'),
+                Some(&directions::S) => render.push('v'),
+                Some(&directions::E) => render.push('>'),
+                Some(&directions::W) => render.push('<'),
+                _ => render.push_str(&grid[(i, j)].to_string()),
+            }
+        }
+        render.push('\n');
+    }
+    (dist, render)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+2413432311323
+3215453535623
+3255245654254
+3446585845452
+4546657867536
+1438598798454
+4457876987766
+3637877979653
+4654967986887
+4564679986453
+1224686865563
+2546548887735
+4322674655533
+";
+
+    const EXAMPLE_UNFORTUNATE: &str = "\
+111111111111
+999999999991
+999999999991
+999999999991
+999999999991
+";
+
+    #[test]
+    fn example() {
+        assert_eq!(aoc::solve::<Day17b>(EXAMPLE), Ok(94));
+    }
+
+    #[test]
+    fn example_unfortunate() {
+        assert_eq!(aoc::solve::<Day17b>(EXAMPLE_UNFORTUNATE), Ok(71));
+    }
+}
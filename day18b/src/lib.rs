@@ -0,0 +1,168 @@
+use aoc::{Line, ParseError, Part, Solution};
+use itertools::Itertools;
+use nom::bytes::complete::{tag, take};
+use nom::character::complete::{digit1, hex_digit1, one_of};
+use nom::{sequence::tuple, IResult};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+enum Dir {
+    North,
+    East,
+    West,
+    South,
+}
+
+impl TryFrom<char> for Dir {
+    type Error = char;
+    fn try_from(c: char) -> Result<Self, char> {
+        match c {
+            '3' => Ok(Self::North),
+            '1' => Ok(Self::South),
+            '2' => Ok(Self::West),
+            '0' => Ok(Self::East),
+            _ => Err(c),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+struct Dig {
+    direction: Dir,
+    count: isize,
+}
+
+impl Dig {
+    fn from_line(l: Line) -> Result<Self, ParseError> {
+        let mut parser = tuple((
+            one_of("UDLR"),
+            tag(" "),
+            digit1,
+            tag(" (#"),
+            take(5usize),
+            hex_digit1,
+            tag(")"),
+        ));
+
+        let result: IResult<&str, _> = parser(l.as_str());
+        let (_, (_, _, _, _, count, direction, _)) = result.map_err(|e| nom_error(l, e))?;
+        let direction = l.piece(&direction[direction.len() - 1..]);
+        Ok(Self {
+            direction: Dir::try_from(direction.as_str().chars().next().unwrap())
+                .map_err(|_| direction.error("a direction digit from 0 to 3"))?,
+            count: isize::from_str_radix(count, 16)
+                .map_err(|_| l.piece(count).error("five hex digits of distance"))?,
+        })
+    }
+}
+
+// point at where nom gave up on a line
+fn nom_error(l: Line, e: nom::Err<nom::error::Error<&str>>) -> ParseError {
+    match e {
+        nom::Err::Error(e) | nom::Err::Failure(e) => l
+            .piece(e.input)
+            .error("a dig step such as \"R 6 (#70c710)\""),
+        nom::Err::Incomplete(_) => l.error_at_end("the rest of the dig step"),
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+struct Pos(isize, isize);
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Polygon {
+    points: Vec<Pos>,
+}
+
+impl Polygon {
+    fn from_digs(digs: Vec<Dig>) -> Polygon {
+        let points = digs
+            .iter()
+            .fold((vec![Pos(0, 0)], Pos(0, 0)), |(mut pts, pt), d| {
+                let p = match d.direction {
+                    Dir::North => Pos(pt.0, pt.1 - d.count),
+                    Dir::East => Pos(pt.0 + d.count, pt.1),
+                    Dir::West => Pos(pt.0 - d.count, pt.1),
+                    Dir::South => Pos(pt.0, pt.1 + d.count),
+                };
+                pts.push(p);
+                (pts, p)
+            })
+            .0;
+        Polygon { points }
+    }
+    fn shoelace(&self) -> isize {
+        1 + self
+            .points
+            .iter()
+            .tuple_windows()
+            .map(|(p1, p2)| -> isize {
+                (p1.0 * p2.1) - (p1.1 * p2.0)
+                    + p1.0.abs_diff(p2.0) as isize
+                    + p1.1.abs_diff(p2.1) as isize
+            })
+            .sum::<isize>()
+            / 2
+    }
+}
+
+pub struct Day18b;
+
+impl Solution for Day18b {
+    const DAY: u8 = 18;
+    const PART: Part = Part::Two;
+    type Input = Polygon;
+    type Answer = isize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let lines: Vec<Line> = aoc::lines(input).collect();
+        let digs = lines
+            .iter()
+            .map(|&l| Dig::from_line(l))
+            .collect::<Result<Vec<Dig>, _>>()?;
+        let poly = Polygon::from_digs(digs);
+        if poly.points.first() != poly.points.last() {
+            return Err(lines[lines.len() - 1].error("a last step back to the start"));
+        }
+        Ok(poly)
+    }
+
+    fn solve(poly: &Self::Input) -> isize {
+        poly.shoelace()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+R 6 (#70c710)
+D 5 (#0dc571)
+L 2 (#5713f0)
+D 2 (#d2c081)
+R 2 (#59c680)
+D 2 (#411b91)
+L 5 (#8ceee2)
+U 2 (#caa173)
+L 1 (#1b58a2)
+U 2 (#caa171)
+R 2 (#7807d2)
+U 3 (#a77fa3)
+L 2 (#015232)
+U 2 (#7a21e3)
+";
+
+    #[test]
+    fn example() {
+        assert_eq!(aoc::solve::<Day18b>(EXAMPLE), Ok(952408144115));
+    }
+
+    #[test]
+    fn bad_plan() {
+        let e = aoc::solve::<Day18b>("R 6 (#000060)\nD 5 (#000051)\nL 6 (#000062)\n").unwrap_err();
+        assert_eq!((e.day, e.line, e.column), (18, 3, 1));
+        assert_eq!(e.expected, "a last step back to the start");
+        let e = aoc::solve::<Day18b>("R 6 (#70c710)\nX 5 (#0dc571)\n").unwrap_err();
+        assert_eq!((e.line, e.column), (2, 1));
+    }
+}
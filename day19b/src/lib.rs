@@ -0,0 +1,380 @@
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::ops::Range;
+
+use aoc::{Line, ParseError, Part, Solution};
+use nom::branch::alt;
+use nom::bytes::complete::{tag, take_until, take_while1};
+use nom::character::complete::{digit1, one_of};
+use nom::combinator::{cut, map, map_res};
+use nom::error::ErrorKind;
+use nom::multi::separated_list1;
+use nom::{sequence::tuple, IResult};
+use pathfinding::directed::count_paths::count_paths;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Category {
+    X,
+    M,
+    A,
+    S,
+    None,
+}
+
+impl TryFrom<char> for Category {
+    type Error = ();
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            'x' => Ok(Category::X),
+            'm' => Ok(Category::M),
+            'a' => Ok(Category::A),
+            's' => Ok(Category::S),
+            _ => Err(()),
+        }
+    }
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Op {
+    LessThan,
+    GreaterThan,
+    None,
+}
+
+impl TryFrom<char> for Op {
+    type Error = ();
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            '<' => Ok(Op::LessThan),
+            '>' => Ok(Op::GreaterThan),
+            _ => Err(()),
+        }
+    }
+}
+#[derive(Debug)]
+struct Step {
+    terminate: bool,
+    category: Category,
+    operation: Op,
+    value: isize,
+    target: String,
+}
+
+impl Step {
+    fn from_str(s: &str) -> IResult<&str, Self> {
+        let mut parser = alt((
+            map(
+                tuple((
+                    one_of::<_, _, nom::error::Error<&str>>("xmas"),
+                    one_of("<>"),
+                    cut(number),
+                    tag(":"),
+                    take_while1(char::is_alphabetic),
+                )),
+                |(category, operation, value, _, target)| Self {
+                    category: Category::try_from(category).unwrap(),
+                    operation: Op::try_from(operation).unwrap(),
+                    value,
+                    target: target.to_string(),
+                    terminate: false,
+                },
+            ),
+            map(take_while1(char::is_alphabetic), |target: &str| Self {
+                category: Category::None,
+                operation: Op::None,
+                value: 0,
+                target: target.to_string(),
+                terminate: true,
+            }),
+        ));
+        parser(s)
+    }
+}
+#[derive(Debug)]
+pub struct Workflow {
+    name: String,
+    steps: Vec<Step>,
+}
+
+impl Workflow {
+    fn from_str(s: &str) -> IResult<&str, Self> {
+        let mut parser = tuple((
+            take_until("{"),
+            tag("{"),
+            separated_list1(tag(","), Step::from_str),
+            tag("}"),
+        ));
+        let (res, (name, _, steps, _)) = parser(s)?;
+        Ok((
+            res,
+            Self {
+                name: name.to_string(),
+                steps,
+            },
+        ))
+    }
+}
+#[derive(Clone)]
+pub struct Rating {
+    values: Vec<(Category, isize)>,
+}
+
+impl Debug for Rating {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{{")?;
+        for &(category, value) in self.values.iter() {
+            write!(f, "{:?}={:?},", category, value)?;
+        }
+        write!(f, "}}")
+    }
+}
+
+impl Rating {
+    fn from_str(s: &str) -> IResult<&str, Self> {
+        let mut parser = tuple((
+            tag("{"),
+            map(
+                separated_list1(
+                    tag(","),
+                    tuple((
+                        map(one_of("xmas"), |c| Category::try_from(c).unwrap()),
+                        tag("="),
+                        cut(number),
+                    )),
+                ),
+                |l| {
+                    l.iter()
+                        .map(|&(a, _, b)| (a, b))
+                        .collect::<Vec<(Category, isize)>>()
+                },
+            ),
+            tag("}"),
+        ));
+        let (res, (_, mut values, _)) = parser(s)?;
+        values.sort_by_key(|x| x.0 as usize);
+        Ok((res, Self { values }))
+    }
+}
+
+// the values are kept below 2^32 so sums and bounds cannot overflow an isize
+fn number(s: &str) -> IResult<&str, isize> {
+    map_res(digit1, |i: &str| i.parse::<u32>().map(|v| v as isize))(s)
+}
+
+// point at where nom gave up on a line
+fn nom_error(l: Line, e: nom::Err<nom::error::Error<&str>>, expected: &str) -> ParseError {
+    match e {
+        nom::Err::Error(e) | nom::Err::Failure(e) if e.code == ErrorKind::MapRes => {
+            l.piece(e.input).error("a number below 2^32")
+        }
+        nom::Err::Error(e) | nom::Err::Failure(e) => l.piece(e.input).error(expected),
+        nom::Err::Incomplete(_) => l.error_at_end(expected),
+    }
+}
+
+// the workflow and where each of its targets sits in the line
+fn workflow(l: Line) -> Result<(Workflow, Vec<Line>), ParseError> {
+    let expected = "a workflow such as \"px{a<2006:qkq,m>2090:A,rfg}\"";
+    match Workflow::from_str(l.as_str()) {
+        Ok(("", w)) if w.steps.last().is_some_and(|s| s.terminate) => {
+            let (_, body) = l.split_once("{")?;
+            let body = body.strip_suffix("}")?;
+            let targets = body.split(",").flat_map(|s| s.split(":").last());
+            Ok((w, targets.collect()))
+        }
+        Ok(("", _)) => Err(l.error("a workflow ending in a step without a condition")),
+        Ok((rest, _)) => Err(l.piece(rest).error("the end of the workflow")),
+        Err(e) => Err(nom_error(l, e, expected)),
+    }
+}
+
+// where a workflow can send a part besides another workflow
+const ENDS: [&str; 2] = ["A", "R"];
+
+// every target is a workflow or an end, and no path from "in" comes back to a
+// workflow on it; the error points at the target that breaks this
+fn check_targets(names: &[String], targets: &[Vec<Line>]) -> Result<(), ParseError> {
+    let index: HashMap<&str, usize> = names
+        .iter()
+        .enumerate()
+        .map(|(i, n)| (n.as_str(), i))
+        .collect();
+    let undefined = |t: &&Line| !index.contains_key(t.as_str()) && !ENDS.contains(&t.as_str());
+    if let Some(t) = targets.iter().flatten().find(undefined) {
+        return Err(t.error("a workflow defined in the list, \"A\" or \"R\""));
+    }
+    // depth first from "in": 1 while on the path, 2 once every path from it ends
+    let mut state = vec![0u8; names.len()];
+    let mut stack = vec![(index["in"], 0)];
+    state[index["in"]] = 1;
+    while let Some((w, next)) = stack.pop() {
+        let Some(t) = targets[w].get(next) else {
+            state[w] = 2;
+            continue;
+        };
+        stack.push((w, next + 1));
+        match index.get(t.as_str()).map(|&i| (i, state[i])) {
+            Some((_, 1)) => {
+                return Err(t.error(format!(
+                    "a target that does not lead back to \"{}\"",
+                    t.as_str()
+                )))
+            }
+            Some((i, 0)) => {
+                state[i] = 1;
+                stack.push((i, 0));
+            }
+            _ => {}
+        }
+    }
+    Ok(())
+}
+
+fn rating(l: Line) -> Result<Rating, ParseError> {
+    match Rating::from_str(l.as_str()) {
+        Ok(("", r)) if r.values.len() == 4 => Ok(r),
+        Ok(("", _)) => Err(l.error("a rating for each of x, m, a and s")),
+        Ok((rest, _)) => Err(l.piece(rest).error("the end of the rating")),
+        Err(e) => Err(nom_error(
+            l,
+            e,
+            "a rating such as \"{x=787,m=2655,a=1222,s=2876}\"",
+        )),
+    }
+}
+
+pub struct Day19b;
+
+impl Solution for Day19b {
+    const DAY: u8 = 19;
+    const PART: Part = Part::Two;
+    type Input = (HashMap<String, Workflow>, Vec<Rating>);
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut lines = aoc::lines(input);
+        let mut workflows = HashMap::new();
+        let (mut names, mut targets) = (Vec::new(), Vec::new());
+        for l in lines.by_ref().take_while(|l| !l.trim().is_empty()) {
+            let (w, t) = workflow(l)?;
+            names.push(w.name.clone());
+            targets.push(t);
+            workflows.insert(w.name.clone(), w);
+        }
+        if !workflows.contains_key("in") {
+            return Err(lines.error_at_end("an \"in\" workflow"));
+        }
+        check_targets(&names, &targets)?;
+        let ratings = lines.map(rating).collect::<Result<Vec<_>, _>>()?;
+        workflows.insert(
+            "R".to_string(),
+            Workflow {
+                name: "R".to_string(),
+                steps: vec![], // terminate dfs here
+            },
+        );
+        Ok((workflows, ratings))
+    }
+
+    fn solve((workflows, _): &Self::Input) -> usize {
+        let successors = |(n, cons): &(String, Vec<Range<isize>>)| {
+            // state with node and current constraints
+            // parse checked that every target is a workflow, "A" or "R"
+            let w = &workflows[n];
+            let mut neigh = Vec::new();
+            let mut conn = cons.clone(); //negative constraints
+            for s in w.steps.iter() {
+                let mut cont = conn.clone(); //positive constraints
+                let cidx = s.category as usize;
+                // a bound outside the range splits it at its end
+                match s.operation {
+                    Op::LessThan => {
+                        let Range { start, end } = conn[cidx];
+                        let v = s.value.clamp(start, end);
+                        cont[cidx] = start..v; // ranges are half-open
+                        conn[cidx] = v..end; //invert the constraint
+                    }
+                    Op::GreaterThan => {
+                        let Range { start, end } = conn[cidx];
+                        let v = (s.value + 1).clamp(start, end);
+                        cont[cidx] = v..end;
+                        conn[cidx] = start..v; //invert the constraint
+                    }
+                    Op::None => {}
+                }
+                neigh.push((s.target.clone(), cont)); //positive for current, negative for old
+            }
+            neigh
+        };
+        let mut s: usize = 0;
+        let success = |(n, cons): &(String, Vec<Range<isize>>)| {
+            *n == "A" && {
+                // just calculate the number of combinations when we are Accepted
+                // dbg!(cons);
+                s += cons
+                    .iter()
+                    .map(|c| (c.end - c.start) as usize)
+                    .product::<usize>();
+                true
+            }
+        };
+        count_paths(("in".to_string(), vec![1..4001; 4]), successors, success);
+        s
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+px{a<2006:qkq,m>2090:A,rfg}
+pv{a>1716:R,A}
+lnx{m>1548:A,A}
+rfg{s<537:gd,x>2440:R,A}
+qs{s>3448:A,lnx}
+qkq{x<1416:A,crn}
+crn{x>2662:A,R}
+in{s<1351:px,qqz}
+qqz{s>2770:qs,m<1801:hdj,R}
+gd{a>3333:R,R}
+hdj{m>838:A,pv}
+
+{x=787,m=2655,a=1222,s=2876}
+{x=1679,m=44,a=2067,s=496}
+{x=2036,m=264,a=79,s=2244}
+{x=2461,m=1339,a=466,s=291}
+{x=2127,m=1623,a=2188,s=1013}
+";
+
+    #[test]
+    fn example() {
+        assert_eq!(aoc::solve::<Day19b>(EXAMPLE), Ok(167409079868000));
+    }
+
+    #[test]
+    fn bounds_outside_the_ratings() {
+        let all = 4000usize.pow(4);
+        assert_eq!(aoc::solve::<Day19b>("in{x<99999:A,R}\n\n"), Ok(all));
+        assert_eq!(aoc::solve::<Day19b>("in{x<0:A,R}\n\n"), Ok(0));
+        assert_eq!(aoc::solve::<Day19b>("in{x>0:R,A}\n\n"), Ok(0));
+        let e = aoc::solve::<Day19b>("in{x<99999999999999999999:A,R}\n\n").unwrap_err();
+        assert_eq!((e.line, e.column), (1, 6));
+        assert_eq!(e.expected, "a number below 2^32");
+    }
+
+    #[test]
+    fn bad_targets() {
+        let e = aoc::solve::<Day19b>("in{x<5:A,px}\n\n").unwrap_err();
+        assert_eq!((e.line, e.column), (1, 10));
+        assert_eq!(e.expected, "a workflow defined in the list, \"A\" or \"R\"");
+        let e = aoc::solve::<Day19b>("in{in}\n\n").unwrap_err();
+        assert_eq!((e.line, e.column), (1, 4));
+        assert_eq!(e.expected, "a target that does not lead back to \"in\"");
+        let e = aoc::solve::<Day19b>("px{A}\nin{x<5:px,qs}\nqs{m>9:R,in}\n\n").unwrap_err();
+        assert_eq!((e.line, e.column), (3, 10));
+        let e = aoc::solve::<Day19b>("px{A}\n\n").unwrap_err();
+        assert_eq!((e.line, e.expected.as_str()), (2, "an \"in\" workflow"));
+        // a loop that "in" never reaches is left alone
+        assert!(aoc::solve::<Day19b>("in{A}\npx{px}\n\n").is_ok());
+    }
+}
@@ -0,0 +1,241 @@
+use std::{
+    collections::{HashMap, VecDeque},
+    fmt::Debug,
+};
+
+use aoc::{ParseError, Part, Solution};
+
+#[derive(PartialEq, Clone, Copy, Default, Debug, Hash, Eq)]
+enum Pulse {
+    #[default]
+    Low,
+    High,
+}
+
+impl Pulse {
+    fn invert(&self) -> Pulse {
+        match self {
+            Pulse::Low => Pulse::High,
+            Pulse::High => Pulse::Low,
+        }
+    }
+}
+
+impl std::ops::Not for Pulse {
+    type Output = Pulse;
+    fn not(self) -> Pulse {
+        self.invert()
+    }
+}
+
+#[derive(Default, Clone, Debug)]
+struct FlipFlop {
+    state: Pulse,
+}
+
+impl Module for FlipFlop {
+    fn tick(&mut self, signal: Pulse, _: String) {
+        if signal == Pulse::Low {
+            self.state = !self.state;
+        }
+    }
+    fn get_state(&self) -> Pulse {
+        self.state
+    }
+    fn cont(&self, signal: Pulse) -> bool {
+        if signal == Pulse::High {
+            return false;
+        }
+        true
+    }
+}
+
+#[derive(Default, Clone, Debug)]
+struct Conjunction {
+    inputs: HashMap<String, Pulse>,
+    state: Pulse,
+}
+
+impl Module for Conjunction {
+    fn tick(&mut self, signal: Pulse, name: String) {
+        self.inputs.insert(name, signal);
+        self.state = match self.inputs.iter().all(|(_, &p)| p == Pulse::High) {
+            true => Pulse::Low,
+            false => Pulse::High,
+        };
+    }
+    fn get_state(&self) -> Pulse {
+        self.state
+    }
+    fn account(&mut self, name: String) {
+        self.inputs.insert(name, Pulse::Low);
+    }
+}
+#[derive(Default, Clone, Debug)]
+struct Broadcaster {
+    state: Pulse,
+}
+
+impl Module for Broadcaster {
+    fn tick(&mut self, signal: Pulse, _: String) {
+        self.state = signal;
+    }
+    fn get_state(&self) -> Pulse {
+        self.state
+    }
+}
+
+trait Module: Debug {
+    fn tick(&mut self, signal: Pulse, name: String);
+    fn get_state(&self) -> Pulse;
+    fn account(&mut self, _: String) {}
+    fn cont(&self, _: Pulse) -> bool {
+        true
+    }
+}
+
+// destinations that need no module of their own
+const SINKS: [&str; 2] = ["rx", "output"];
+
+// module kind ('%', '&' or 'b') and destinations of every module, by name
+pub type Wiring = (HashMap<String, char>, HashMap<String, Vec<String>>);
+
+fn modules((kinds, e): &Wiring) -> HashMap<String, Box<dyn Module>> {
+    let mut g: HashMap<String, Box<dyn Module>> = kinds
+        .iter()
+        .map(|(name, kind)| {
+            let comp: Box<dyn Module> = match kind {
+                '%' => Box::new(FlipFlop::default()),
+                '&' => Box::new(Conjunction::default()),
+                _ => Box::new(Broadcaster::default()),
+            };
+            (name.clone(), comp)
+        })
+        .collect();
+    for (k, v) in e.iter() {
+        for i in v {
+            if let Some(n) = g.get_mut(i) {
+                n.account(k.clone())
+            }
+        }
+    }
+    g
+}
+
+pub struct Day20a;
+
+impl Solution for Day20a {
+    const DAY: u8 = 20;
+    const PART: Part = Part::One;
+    type Input = Wiring;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut kinds = HashMap::new();
+        let mut e = HashMap::new();
+        let mut targets = Vec::new();
+        let mut lines = aoc::lines(input);
+        for l in lines.by_ref() {
+            let (src, dst) = l.split_once(" -> ")?;
+            let (kind, name) = match src.as_str().split_at(1) {
+                ("%", name) => ('%', name),
+                ("&", name) => ('&', name),
+                _ if src.as_str() == "broadcaster" => ('b', "broadcaster"),
+                _ => return Err(src.error("'%' or '&' and a name, or \"broadcaster\"")),
+            };
+            if kinds.insert(name.trim().to_string(), kind).is_some() {
+                return Err(src.error("a module that has not been defined before"));
+            }
+            let neigh: Vec<_> = dst.split(",").map(|s| s.trim()).collect();
+            e.insert(
+                name.trim().to_string(),
+                neigh.iter().map(|s| s.as_str().to_string()).collect(),
+            );
+            targets.extend(neigh);
+        }
+        if !kinds.contains_key("broadcaster") {
+            return Err(lines.error_at_end("a \"broadcaster\" module"));
+        }
+        if let Some(t) = targets
+            .iter()
+            .find(|t| !kinds.contains_key(t.as_str()) && !SINKS.contains(&t.as_str()))
+        {
+            return Err(t.error("a module defined in the wiring, \"rx\" or \"output\""));
+        }
+        Ok((kinds, e))
+    }
+
+    fn solve(wiring: &Self::Input) -> usize {
+        let mut g = modules(wiring);
+        let e = &wiring.1;
+        let mut hc = 0;
+        let mut lc = 0;
+        for _ in 0..1000 {
+            let mut q = VecDeque::new();
+            lc += 1;
+            // println!("button Low -> broadcaster");
+            q.push_back(("broadcaster".to_string(), Pulse::Low));
+            while let Some((name, signal)) = q.pop_front() {
+                for n in &e[&name] {
+                    // println!("{} {:?} -> {}", name, signal, n);
+                    match signal {
+                        Pulse::Low => lc += 1,
+                        Pulse::High => hc += 1,
+                    }
+                    if SINKS.contains(&n.as_str()) {
+                        continue;
+                    }
+                    let neigh = g.get_mut(n).unwrap();
+                    if !neigh.cont(signal) {
+                        continue;
+                    }
+                    neigh.tick(signal, name.clone());
+                    q.push_back((n.to_string(), neigh.get_state()));
+                }
+            }
+        }
+        lc * hc
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE_LOOP: &str = "\
+broadcaster -> a, b, c
+%a -> b
+%b -> c
+%c -> inv
+&inv -> a
+";
+
+    const EXAMPLE_OUTPUT: &str = "\
+broadcaster -> a
+%a -> inv, con
+&inv -> b
+%b -> con
+&con -> output
+";
+
+    #[test]
+    fn example_loop() {
+        assert_eq!(aoc::solve::<Day20a>(EXAMPLE_LOOP), Ok(32000000));
+    }
+
+    #[test]
+    fn example_output() {
+        assert_eq!(aoc::solve::<Day20a>(EXAMPLE_OUTPUT), Ok(11687500));
+    }
+
+    #[test]
+    fn bad_wiring() {
+        let e = aoc::solve::<Day20a>("%a -> b\n&b -> rx\n").unwrap_err();
+        assert_eq!(
+            (e.line, e.expected.as_str()),
+            (3, "a \"broadcaster\" module")
+        );
+        let e = aoc::solve::<Day20a>("broadcaster -> a\n%a -> b, rx\n").unwrap_err();
+        assert_eq!((e.line, e.column), (2, 7));
+    }
+}
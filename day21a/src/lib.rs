@@ -0,0 +1,102 @@
+use std::collections::HashSet;
+
+use aoc::{Line, ParseError, Part, Solution};
+use grid::{Grid, Pos};
+
+// the garden plots one step away from p
+fn neigh(g: &Grid<u8>, p: Pos) -> impl Iterator<Item = Pos> + '_ {
+    g.neighbours4(p).filter(|&n| g[n] != b'#')
+}
+
+// a map of plots and rocks with exactly one S
+fn garden(input: &str) -> Result<Grid<u8>, ParseError> {
+    let lines: Vec<Line> = aoc::lines(input).collect();
+    let rows = aoc::rows(lines.iter().copied(), "'.', '#' or 'S'", |c| {
+        ".#S".contains(c).then_some(c as u8)
+    })?;
+    let mut starts = lines
+        .iter()
+        .flat_map(|l| l.as_str().match_indices('S').map(|(_, s)| l.piece(s)));
+    if starts.next().is_none() {
+        return Err(lines[lines.len() - 1].error_at_end("an 'S'"));
+    }
+    if let Some(other) = starts.next() {
+        return Err(other.error("only one 'S'"));
+    }
+    Ok(Grid::from_rows(rows))
+}
+
+pub struct Day21a;
+
+impl Solution for Day21a {
+    const DAY: u8 = 21;
+    const PART: Part = Part::One;
+    type Input = Grid<u8>;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        garden(input)
+    }
+
+    fn solve(g: &Self::Input) -> usize {
+        solve_and_render(g).0
+    }
+}
+
+// plots reachable in exactly `steps` steps from S
+fn walk(g: &Grid<u8>, steps: usize) -> HashSet<Pos> {
+    let mut queue = HashSet::from([g.position(|&c| c == b'S').unwrap()]);
+    let mut next = HashSet::new();
+    for _ in 0..steps {
+        for p in queue.drain() {
+            for n in neigh(g, p) {
+                next.insert(n);
+            }
+        }
+        queue = next.clone();
+        next.clear();
+    }
+    queue
+}
+
+// the reachable plot count and the garden with those plots marked O
+pub fn solve_and_render(g: &Grid<u8>) -> (usize, String) {
+    let queue = walk(g, 64);
+    let render = g.render(|p, &c| match queue.contains(&p) {
+        true => 'O',
+        false => c as char,
+    });
+    (queue.len(), render)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+...........
+.....###.#.
+.###.##..#.
+..#.#...#..
+....#.#....
+.##..S####.
+.##..#...#.
+.......##..
+.##.#.####.
+.##..##.##.
+...........
+";
+
+    #[test]
+    fn example() {
+        assert_eq!(walk(&Day21a::parse(EXAMPLE).unwrap(), 6).len(), 16);
+    }
+
+    #[test]
+    fn bad_map() {
+        let e = aoc::solve::<Day21a>("...\n.#.\n...\n").unwrap_err();
+        assert_eq!((e.day, e.expected.as_str()), (21, "an 'S'"));
+        let e = aoc::solve::<Day21a>("S..\n.#.\n..S\n").unwrap_err();
+        assert_eq!((e.line, e.column), (3, 3));
+    }
+}
@@ -0,0 +1,140 @@
+use std::collections::HashSet;
+
+use aoc::{Line, ParseError, Part, Solution};
+use grid::{Grid, Pos};
+use nalgebra::{Matrix3, Vector3};
+
+// the garden plots one step away from p on the infinitely repeated map
+fn neigh(g: &Grid<u8>, p: Pos) -> impl Iterator<Item = Pos> + '_ {
+    p.neighbours4()
+        .into_iter()
+        .filter(|&n| *g.get_wrapped(n) != b'#')
+}
+
+fn count(g: &Grid<u8>, n: usize, with_round: bool) -> usize {
+    let mut queue = HashSet::from([g.position(|&c| c == b'S').unwrap()]);
+    let mut next = HashSet::new();
+    for _ in 0..n {
+        for p in queue.drain() {
+            for n in neigh(g, p) {
+                next.insert(n);
+            }
+        }
+        queue = next.clone();
+        next.clear();
+    }
+    if with_round {
+        queue
+            .iter()
+            .map(|&p| g.bounds().wrap(p))
+            .collect::<HashSet<Pos>>()
+            .len()
+    } else {
+        queue.len()
+    }
+}
+
+// a square map of plots and rocks with exactly one S
+fn garden(input: &str) -> Result<Grid<u8>, ParseError> {
+    let lines: Vec<Line> = aoc::lines(input).collect();
+    let rows = aoc::rows(lines.iter().copied(), "'.', '#' or 'S'", |c| {
+        ".#S".contains(c).then_some(c as u8)
+    })?;
+    let mut starts = lines
+        .iter()
+        .flat_map(|l| l.as_str().match_indices('S').map(|(_, s)| l.piece(s)));
+    if starts.next().is_none() {
+        return Err(lines[lines.len() - 1].error_at_end("an 'S'"));
+    }
+    if let Some(other) = starts.next() {
+        return Err(other.error("only one 'S'"));
+    }
+    let width = rows[0].len();
+    if lines.len() > width {
+        return Err(lines[width].error(format!("a square map of {} rows", width)));
+    }
+    if lines.len() < width {
+        let expected = format!("{} more rows for a square map", width - lines.len());
+        return Err(lines[lines.len() - 1].error_at_end(expected));
+    }
+    Ok(Grid::from_rows(rows))
+}
+
+pub struct Day21b;
+
+impl Solution for Day21b {
+    const DAY: u8 = 21;
+    const PART: Part = Part::Two;
+    type Input = Grid<u8>;
+    type Answer = f64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        garden(input)
+    }
+
+    // fits a quadratic to the plots after n % width steps and one and two map
+    // widths more, which holds for the puzzle's layout
+    fn solve(g: &Self::Input) -> f64 {
+        let n = 26501365;
+        let w = g.width();
+        let s = g.position(|&c| c == b'S').unwrap();
+        if s != Pos(w as i32 / 2, w as i32 / 2) {
+            aoc::warn("S is not in the middle of the map");
+        }
+        if g.row(s.1 as usize).contains(&b'#') || g.column(s.0 as usize).any(|&c| c == b'#') {
+            aoc::warn("the row or column through S has rocks on it");
+        }
+        if n % w != w / 2 {
+            aoc::warn(format!("{} steps do not end on the edge of a map", n));
+        }
+        let b = Vector3::from_iterator(
+            (0..3).map(|i| count(g, (i * g.width()) + n % g.width(), false) as f64),
+        );
+        // dbg!(&b); // [3797, 34009, 94353]
+        let a = Matrix3::new(0., 0., 1., 1., 1., 1., 4., 2., 1.);
+        let x = a.lu().solve(&b).unwrap(); // [15066, 15146, 3797]
+        let p = (n / g.width()) as f64;
+        let y = Vector3::new(p * p, p, 1.);
+        x.dot(&y)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+...........
+.....###.#.
+.###.##..#.
+..#.#...#..
+....#.#....
+.##..S####.
+.##..#...#.
+.......##..
+.##.#.####.
+.##..##.##.
+...........
+";
+
+    #[test]
+    fn example() {
+        let g = Day21b::parse(EXAMPLE).unwrap();
+        for (steps, expected) in [(6, 16), (10, 50), (50, 1594), (100, 6536)] {
+            assert_eq!(count(&g, steps, false), expected);
+        }
+        // the example breaks the layout the quadratic fit relies on
+        Day21b::solve(&g);
+        assert_eq!(aoc::take_warnings().len(), 2);
+    }
+
+    #[test]
+    fn bad_map() {
+        let e = aoc::solve::<Day21b>("...\n.#.\n...\n").unwrap_err();
+        assert_eq!(e.expected, "an 'S'");
+        let e = aoc::solve::<Day21b>("...\n.S.\n...\n...\n").unwrap_err();
+        assert_eq!((e.line, e.expected.as_str()), (4, "a square map of 3 rows"));
+        let e = aoc::solve::<Day21b>(".....\n..S..\n.....\n").unwrap_err();
+        assert_eq!(e.expected, "2 more rows for a square map");
+    }
+}
@@ -0,0 +1,178 @@
+use std::collections::{HashMap, HashSet};
+
+use aoc::{ParseError, Part, Solution};
+use pathfinding::{
+    directed::bfs,
+    matrix::{
+        directions::{self, DIRECTIONS_4},
+        Matrix,
+    },
+};
+use petgraph::{algo::all_simple_paths, dot::Dot, graph::NodeIndex, Directed, Graph};
+
+pub type Trails = Graph<(usize, usize), i32, Directed>;
+
+// the trail enters and leaves through the top and bottom rows, next to the corners
+fn trail_end(grid: &Matrix<u8>, (row, col): (usize, usize)) -> Result<(), ParseError> {
+    match grid.get((row, col)) {
+        Some(b'.') => Ok(()),
+        found => Err(ParseError {
+            day: 0,
+            line: row + 1,
+            column: col + 1,
+            expected: "'.' where the trail meets the edge".to_string(),
+            found: found.map_or("end of line".to_string(), |&c| format!("{:?}", c as char)),
+        }),
+    }
+}
+
+// junctions of the trail map with the length of the trail between them
+fn compact(grid: Matrix<u8>) -> (Trails, NodeIndex, NodeIndex) {
+    let start = (0, 1); // row, col
+    let end = (grid.rows - 1, grid.columns - 2);
+    let successors = |&p: &(usize, usize)| {
+        let mut neigh = Vec::new();
+        match grid.get(p) {
+            Some(b'#') => (),
+            Some(b'.') => {
+                for d in DIRECTIONS_4 {
+                    if let Some(np) = grid.move_in_direction(p, d) {
+                        neigh.push(np);
+                    }
+                }
+            }
+            Some(b'>') => {
+                if let Some(np) = grid.move_in_direction(p, directions::E) {
+                    neigh.push(np)
+                }
+            }
+            Some(b'<') => {
+                if let Some(np) = grid.move_in_direction(p, directions::W) {
+                    neigh.push(np)
+                }
+            }
+            Some(b'^') => {
+                if let Some(np) = grid.move_in_direction(p, directions::N) {
+                    neigh.push(np)
+                }
+            }
+            Some(b'v') => {
+                if let Some(np) = grid.move_in_direction(p, directions::S) {
+                    neigh.push(np)
+                }
+            }
+            _ => panic!("Unexpected grid value"),
+        };
+        neigh
+            .into_iter()
+            .filter(|&p| grid.get(p) != Some(&b'#'))
+            .collect::<Vec<_>>()
+    };
+    let mut g: Graph<(usize, usize), i32, Directed> = Graph::new();
+    let mut h = HashMap::new();
+    let mut nodes = Vec::new();
+    for row in 0..grid.rows {
+        for col in 0..grid.columns {
+            let p = (row, col);
+            if grid.get(p) != Some(&b'#') && (successors(&p).len() > 2 || p == start || p == end) {
+                h.insert(p, g.add_node(p));
+                nodes.push(p);
+            }
+        }
+    }
+    // compacted
+    // println!("{}", g.node_count());
+    for &n in nodes.iter() {
+        let mut seen = HashSet::new();
+        for _ in 0..1.max(successors(&n).len() - 1) {
+            let path = bfs::bfs(
+                &n,
+                |neigh| match seen.contains(neigh) {
+                    true => vec![],
+                    false => successors(neigh),
+                },
+                |neigh| *neigh != n && h.contains_key(neigh) && !seen.contains(neigh),
+            );
+            if let Some(path) = path {
+                let neigh = *path.last().unwrap();
+                seen.insert(neigh);
+                g.add_edge(
+                    *h.get(&n).unwrap(),
+                    *h.get(&neigh).unwrap(),
+                    path.len() as i32 - 1,
+                );
+            }
+        }
+    }
+    (g, *h.get(&start).unwrap(), *h.get(&end).unwrap())
+}
+
+pub fn dot((g, _, _): &(Trails, NodeIndex, NodeIndex)) -> String {
+    format!("{:?}", Dot::with_config(&g, &[]))
+}
+
+pub struct Day23a;
+
+impl Solution for Day23a {
+    const DAY: u8 = 23;
+    const PART: Part = Part::One;
+    type Input = (Trails, NodeIndex, NodeIndex);
+    type Answer = i32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let rows = aoc::rows(aoc::lines(input), "'#', '.' or a slope", |c| {
+            "#.<>^v".contains(c).then_some(c as u8)
+        })?;
+        let grid = rows.into_iter().collect::<Matrix<u8>>();
+        trail_end(&grid, (0, 1))?;
+        trail_end(&grid, (grid.rows - 1, grid.columns.saturating_sub(2)))?;
+        Ok(compact(grid))
+    }
+
+    fn solve((g, start, end): &Self::Input) -> i32 {
+        all_simple_paths::<Vec<_>, _>(g, *start, *end, 0, None)
+            .map(|path| {
+                path.windows(2)
+                    .map(|w| g.edges_connecting(w[0], w[1]).next().unwrap().weight())
+                    .sum::<i32>()
+            })
+            .max()
+            .unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+#.#####################
+#.......#########...###
+#######.#########.#.###
+###.....#.>.>.###.#.###
+###v#####.#v#.###.#.###
+###.>...#.#.#.....#...#
+###v###.#.#.#########.#
+###...#.#.#.......#...#
+#####.#.#.#######.#.###
+#.....#.#.#.......#...#
+#.#####.#.#.#########v#
+#.#...#...#...###...>.#
+#.#.#v#######v###.###v#
+#...#.>.#...>.>.#.###.#
+#####v#.#.###v#.#.###.#
+#.....#...#...#.#.#...#
+#.#########.###.#.#.###
+#...###...#...#...#.###
+###.###.#.###v#####v###
+#...#...#.#.>.>.#.>.###
+#.###.###.#.###.#.#v###
+#.....###...###...#...#
+#####################.#
+";
+
+    #[test]
+    fn example() {
+        assert_eq!(aoc::solve::<Day23a>(EXAMPLE), Ok(94));
+    }
+}
@@ -0,0 +1,103 @@
+use std::collections::HashMap;
+
+use aoc::{ParseError, Part, Solution};
+use petgraph::{
+    dot::{Config, Dot},
+    Graph, Undirected,
+};
+use rustworkx_core::connectivity::stoer_wagner_min_cut;
+
+fn wiring(input: &str) -> Result<Graph<String, i32, Undirected>, ParseError> {
+    let mut h: HashMap<String, Vec<String>> = HashMap::new();
+    for l in aoc::lines(input) {
+        let (parent, children) = l.split_once(":")?;
+        let children: Vec<String> = children.words().map(|c| c.as_str().to_string()).collect();
+        if children.is_empty() {
+            return Err(l.error_at_end("the components it is wired to"));
+        }
+        h.insert(parent.trim().as_str().to_string(), children);
+    }
+    // dbg!(&h);
+    let mut g = Graph::new_undirected();
+    let mut nodes = HashMap::new();
+    for (k, children) in h.iter() {
+        let src = *nodes.entry(k).or_insert_with(|| g.add_node(k.clone()));
+        for child in children {
+            let dst = *nodes
+                .entry(child)
+                .or_insert_with(|| g.add_node(child.clone()));
+            g.add_edge(src, dst, 1);
+        }
+    }
+    Ok(g)
+}
+
+// render with neato
+pub fn dot(g: &Graph<String, i32, Undirected>) -> String {
+    format!("{:?}", Dot::with_config(g, &[Config::EdgeNoLabel]))
+}
+
+pub struct Day25a;
+
+impl Solution for Day25a {
+    const DAY: u8 = 25;
+    const PART: Part = Part::One;
+    type Input = Graph<String, i32, Undirected>;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        wiring(input)
+    }
+
+    fn solve(g: &Self::Input) -> usize {
+        // why code when dot works?
+        // for cut in vec![("xxq", "hqq"), ("vkd", "qfb"), ("xzz", "kgl")] {
+        //     g.remove_node(*nodes.get(&cut.0.to_string()).unwrap())
+        //         .expect("check graph");
+        //     g.remove_node(*nodes.get(&cut.1.to_string()).unwrap())
+        //         .expect("check graph");
+        // }
+        // let n = g.node_count();
+        // let mut c = 0;
+        // let mut dfs = Dfs::new(&g, g.node_indices().next().unwrap());
+        // while let Some(_) = dfs.next(&g) {
+        //     c += 1;
+        // }
+        // println!("{}", (c + 3) * (n - c + 3));
+        let min_cut_res: Result<Option<(usize, Vec<_>)>, _> =
+            stoer_wagner_min_cut(&g, |_| Ok::<usize, usize>(1));
+        match min_cut_res {
+            Ok(Some((cut, part))) => {
+                assert!(cut == 3);
+                part.len() * (g.node_count() - part.len())
+            }
+            _ => panic!("no minimum cut found"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+jqt: rhn xhk nvd
+rsh: frs pzl lsr
+xhk: hfx
+cmg: qnr nvd lhk bvb
+rhn: xhk bvb hfx
+bvb: xhk hfx
+pzl: lsr hfx nvd
+qnr: nvd
+ntq: jqt hfx bvb xhk
+nvd: lhk
+lsr: lhk
+rzs: qnr cmg lsr rsh
+frs: qnr lhk lsr
+";
+
+    #[test]
+    fn example() {
+        assert_eq!(aoc::solve::<Day25a>(EXAMPLE), Ok(54));
+    }
+}
@@ -0,0 +1,308 @@
+use std::ops::{Index, IndexMut};
+
+// x grows east, y grows south, (0, 0) is the top left cell
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Pos(pub i32, pub i32);
+
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Dir {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Dir {
+    pub const ALL: [Dir; 4] = [Dir::North, Dir::East, Dir::South, Dir::West];
+
+    pub fn invert(self) -> Dir {
+        match self {
+            Dir::North => Dir::South,
+            Dir::East => Dir::West,
+            Dir::South => Dir::North,
+            Dir::West => Dir::East,
+        }
+    }
+    pub fn delta(self) -> (i32, i32) {
+        match self {
+            Dir::North => (0, -1),
+            Dir::East => (1, 0),
+            Dir::South => (0, 1),
+            Dir::West => (-1, 0),
+        }
+    }
+}
+
+impl Pos {
+    pub fn step(self, d: Dir) -> Pos {
+        let (dx, dy) = d.delta();
+        Pos(self.0 + dx, self.1 + dy)
+    }
+    pub fn north(self) -> Pos {
+        self.step(Dir::North)
+    }
+    pub fn south(self) -> Pos {
+        self.step(Dir::South)
+    }
+    pub fn east(self) -> Pos {
+        self.step(Dir::East)
+    }
+    pub fn west(self) -> Pos {
+        self.step(Dir::West)
+    }
+    pub fn neighbours4(self) -> [Pos; 4] {
+        Dir::ALL.map(|d| self.step(d))
+    }
+    // the four orthogonal neighbours followed by the four diagonal ones
+    pub fn neighbours8(self) -> [Pos; 8] {
+        let Pos(x, y) = self;
+        [
+            Pos(x, y - 1),
+            Pos(x + 1, y),
+            Pos(x, y + 1),
+            Pos(x - 1, y),
+            Pos(x + 1, y - 1),
+            Pos(x + 1, y + 1),
+            Pos(x - 1, y + 1),
+            Pos(x - 1, y - 1),
+        ]
+    }
+    pub fn manhattan(self, other: Pos) -> u32 {
+        self.0.abs_diff(other.0) + self.1.abs_diff(other.1)
+    }
+}
+
+// 0 <= x < width and 0 <= y < height
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Bounds {
+    pub width: usize,
+    pub height: usize,
+}
+
+impl Bounds {
+    pub fn contains(&self, p: Pos) -> bool {
+        p.0 >= 0 && p.1 >= 0 && (p.0 as usize) < self.width && (p.1 as usize) < self.height
+    }
+    // the same cell on a plane tiled with copies of these bounds
+    pub fn wrap(&self, p: Pos) -> Pos {
+        Pos(
+            p.0.rem_euclid(self.width as i32),
+            p.1.rem_euclid(self.height as i32),
+        )
+    }
+    // every position, row by row
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| Pos(x as i32, y as i32)))
+    }
+}
+
+// rectangular grid of cells stored row by row
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Grid<T> {
+    bounds: Bounds,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid {
+            bounds: Bounds { width, height },
+            cells: vec![fill; width * height],
+        }
+    }
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Self {
+        let width = rows.first().map_or(0, |r| r.len());
+        assert!(
+            rows.iter().all(|r| r.len() == width),
+            "rows of a grid must all have the same length"
+        );
+        Grid {
+            bounds: Bounds {
+                width,
+                height: rows.len(),
+            },
+            cells: rows.into_iter().flatten().collect(),
+        }
+    }
+    // one row per non-empty line, one cell per character
+    pub fn parse_with(text: &str, mut cell: impl FnMut(char) -> T) -> Self {
+        Self::from_rows(
+            text.trim_end()
+                .lines()
+                .map(|l| l.chars().map(&mut cell).collect())
+                .collect(),
+        )
+    }
+
+    pub fn width(&self) -> usize {
+        self.bounds.width
+    }
+    pub fn height(&self) -> usize {
+        self.bounds.height
+    }
+    pub fn bounds(&self) -> Bounds {
+        self.bounds
+    }
+    pub fn contains(&self, p: Pos) -> bool {
+        self.bounds.contains(p)
+    }
+
+    fn offset(&self, p: Pos) -> usize {
+        p.1 as usize * self.bounds.width + p.0 as usize
+    }
+    pub fn get(&self, p: Pos) -> Option<&T> {
+        self.contains(p).then(|| &self.cells[self.offset(p)])
+    }
+    pub fn get_mut(&mut self, p: Pos) -> Option<&mut T> {
+        if !self.contains(p) {
+            return None;
+        }
+        let offset = self.offset(p);
+        Some(&mut self.cells[offset])
+    }
+    // lookup on the infinite plane tiled with copies of this grid
+    pub fn get_wrapped(&self, p: Pos) -> &T {
+        &self[self.bounds.wrap(p)]
+    }
+
+    // the neighbour in direction d, if it is on the grid
+    pub fn step(&self, p: Pos, d: Dir) -> Option<Pos> {
+        Some(p.step(d)).filter(|&n| self.contains(n))
+    }
+    pub fn neighbours4(&self, p: Pos) -> impl Iterator<Item = Pos> + '_ {
+        p.neighbours4().into_iter().filter(|&n| self.contains(n))
+    }
+    pub fn neighbours8(&self, p: Pos) -> impl Iterator<Item = Pos> + '_ {
+        p.neighbours8().into_iter().filter(|&n| self.contains(n))
+    }
+
+    // every position, row by row
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        self.bounds.positions()
+    }
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+    pub fn position(&self, mut pred: impl FnMut(&T) -> bool) -> Option<Pos> {
+        self.iter().find(|(_, c)| pred(c)).map(|(p, _)| p)
+    }
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.bounds.width..(y + 1) * self.bounds.width]
+    }
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.bounds.height).map(|y| self.row(y))
+    }
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        self.cells.iter().skip(x).step_by(self.bounds.width.max(1))
+    }
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.bounds.width).map(|x| self.column(x))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            bounds: self.bounds,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+    // one line per row, one character per cell
+    pub fn render(&self, mut f: impl FnMut(Pos, &T) -> char) -> String {
+        let mut out = String::new();
+        for (p, c) in self.iter() {
+            out.push(f(p, c));
+            if p.0 as usize == self.bounds.width - 1 {
+                out.push('\n');
+            }
+        }
+        out
+    }
+}
+
+impl Grid<u8> {
+    pub fn parse(text: &str) -> Self {
+        Self::from_rows(
+            text.trim_end()
+                .lines()
+                .map(|l| l.as_bytes().to_vec())
+                .collect(),
+        )
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+    fn index(&self, p: Pos) -> &T {
+        assert!(self.contains(p), "{:?} is outside the grid", p);
+        &self.cells[self.offset(p)]
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, p: Pos) -> &mut T {
+        assert!(self.contains(p), "{:?} is outside the grid", p);
+        let offset = self.offset(p);
+        &mut self.cells[offset]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEXT: &str = "\
+ab.
+#.c
+";
+
+    #[test]
+    fn parse_rectangular() {
+        let g = Grid::parse(TEXT);
+        assert_eq!((g.width(), g.height()), (3, 2));
+        assert_eq!(g[Pos(2, 1)], b'c');
+        assert_eq!(g.get(Pos(0, 2)), None);
+        assert_eq!(g.get(Pos(3, 0)), None);
+        assert_eq!(g.get(Pos(-1, 0)), None);
+    }
+
+    #[test]
+    fn parse_crlf_and_trailing_blank_lines() {
+        assert_eq!(Grid::parse("ab.\r\n#.c\r\n\r\n"), Grid::parse(TEXT));
+    }
+
+    #[test]
+    fn neighbours_stay_inside() {
+        let g = Grid::parse(TEXT);
+        assert_eq!(
+            g.neighbours4(Pos(0, 0)).collect::<Vec<_>>(),
+            vec![Pos(1, 0), Pos(0, 1)]
+        );
+        assert_eq!(g.neighbours8(Pos(1, 0)).count(), 5);
+        assert_eq!(g.step(Pos(2, 1), Dir::East), None);
+        assert_eq!(g.step(Pos(2, 1), Dir::North), Some(Pos(2, 0)));
+    }
+
+    #[test]
+    fn wrapped_lookup() {
+        let g = Grid::parse(TEXT);
+        assert_eq!(*g.get_wrapped(Pos(-1, -1)), b'c');
+        assert_eq!(*g.get_wrapped(Pos(6, 3)), b'#');
+        assert_eq!(g.bounds().wrap(Pos(-4, 5)), Pos(2, 1));
+    }
+
+    #[test]
+    fn rows_and_columns() {
+        let g = Grid::parse(TEXT);
+        assert_eq!(g.rows().collect::<Vec<_>>(), vec![b"ab.", b"#.c"]);
+        assert_eq!(
+            g.columns()
+                .map(|c| c.copied().collect::<Vec<_>>())
+                .collect::<Vec<_>>(),
+            vec![b"a#".to_vec(), b"b.".to_vec(), b".c".to_vec()]
+        );
+        assert_eq!(g.render(|_, &c| c as char), TEXT);
+    }
+}
@@ -0,0 +1,82 @@
+use std::any::Any;
+use std::cell::RefCell;
+use std::fs;
+use std::io;
+use std::panic::{self, PanicHookInfo};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+use aoc::ParseError;
+
+thread_local! {
+    // where the last panic on this thread happened, filled in by the quiet hook
+    static PANIC_AT: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+// replace the default hook, which prints a backtrace per panic, with one that
+// only remembers the location so it can go into the table
+pub fn quiet_panics() {
+    panic::set_hook(Box::new(|info: &PanicHookInfo| {
+        let at = info.location().map(|l| l.to_string());
+        PANIC_AT.with(|p| *p.borrow_mut() = at);
+    }));
+}
+
+fn message(payload: &(dyn Any + Send)) -> String {
+    if let Some(s) = payload.downcast_ref::<&str>() {
+        s.to_string()
+    } else if let Some(s) = payload.downcast_ref::<String>() {
+        s.clone()
+    } else {
+        "unknown panic".to_string()
+    }
+}
+
+// the answer of one part on one input, or why there is none;
+// a panic in the solution is caught and reported like a parse error
+pub fn attempt(
+    solve: fn(&str) -> Result<String, ParseError>,
+    input: &str,
+) -> (Result<String, String>, Duration) {
+    PANIC_AT.with(|p| p.borrow_mut().take());
+    aoc::take_warnings();
+    let start = Instant::now();
+    let outcome = panic::catch_unwind(|| solve(input));
+    let elapsed = start.elapsed();
+    let result = match outcome {
+        Ok(Ok(answer)) => Ok(answer),
+        Ok(Err(e)) => Err(e.to_string()),
+        Err(payload) => match PANIC_AT.with(|p| p.borrow_mut().take()) {
+            Some(at) => Err(format!("panicked at {}: {}", at, message(&*payload))),
+            None => Err(format!("panicked: {}", message(&*payload))),
+        },
+    };
+    (result, elapsed)
+}
+
+// the files in dir, sorted by name, skipping hidden ones and subdirectories
+pub fn inputs(dir: &Path) -> io::Result<Vec<PathBuf>> {
+    let mut paths = Vec::new();
+    for entry in fs::read_dir(dir)? {
+        let entry = entry?;
+        let hidden = entry.file_name().to_string_lossy().starts_with('.');
+        if !hidden && entry.file_type()?.is_file() {
+            paths.push(entry.path());
+        }
+    }
+    paths.sort();
+    Ok(paths)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn panics_become_failures() {
+        let (result, _) = attempt(|input| Ok(input.trim().to_string()), "42\n");
+        assert_eq!(result, Ok("42".to_string()));
+        let (result, _) = attempt(|input| Ok(input.parse::<u8>().unwrap().to_string()), "x");
+        assert!(result.unwrap_err().contains("ParseIntError"));
+    }
+}
@@ -0,0 +1,123 @@
+use std::collections::HashMap;
+use std::fmt::Write;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use aoc::{ParseError, Solution};
+
+// keep repeating a part until it has run this long, so fast days get a stable median
+const TARGET: Duration = Duration::from_secs(1);
+const MAX_RUNS: usize = 100;
+
+#[derive(Clone, Copy, Debug)]
+pub struct Timing {
+    pub parse: Duration,
+    pub solve: Duration,
+    pub runs: usize,
+}
+
+fn median(mut samples: Vec<Duration>) -> Duration {
+    samples.sort();
+    samples[samples.len() / 2]
+}
+
+// median parse and solve time of one part, measured separately;
+// an input that does not parse is reported before any timing starts
+pub fn measure<S: Solution>(input: &str) -> Result<Timing, ParseError> {
+    aoc::parse::<S>(input)?;
+    let mut parse = Vec::new();
+    let mut solve = Vec::new();
+    let start = Instant::now();
+    while parse.len() < MAX_RUNS && (parse.is_empty() || start.elapsed() < TARGET) {
+        let t = Instant::now();
+        let parsed = black_box(S::parse(black_box(input))).unwrap();
+        parse.push(t.elapsed());
+        let t = Instant::now();
+        black_box(S::solve(&parsed));
+        solve.push(t.elapsed());
+    }
+    Ok(Timing {
+        runs: parse.len(),
+        parse: median(parse),
+        solve: median(solve),
+    })
+}
+
+// one line per part, "DAY PART PARSE_NS SOLVE_NS RUNS", sorted like the runner lists them
+pub fn format(results: &[(String, Timing)]) -> String {
+    let mut out = String::from("# day part parse_ns solve_ns runs\n");
+    for (name, t) in results {
+        let (day, part) = name.split_at(2);
+        writeln!(
+            out,
+            "{} {} {} {} {}",
+            day,
+            part,
+            t.parse.as_nanos(),
+            t.solve.as_nanos(),
+            t.runs
+        )
+        .unwrap();
+    }
+    out
+}
+
+// parse and solve time per part name (e.g. "17b") from a file written by `format`
+pub fn parse_baseline(text: &str) -> HashMap<String, (Duration, Duration)> {
+    text.lines()
+        .filter(|l| !l.starts_with('#') && !l.trim().is_empty())
+        .filter_map(|l| {
+            let f: Vec<&str> = l.split_ascii_whitespace().collect();
+            let ns = |s: &str| s.parse().ok().map(Duration::from_nanos);
+            match f[..] {
+                [day, part, parse, solve, ..] => {
+                    Some((format!("{}{}", day, part), (ns(parse)?, ns(solve)?)))
+                }
+                _ => None,
+            }
+        })
+        .collect()
+}
+
+// relative change against the baseline, e.g. "+12.5%"
+pub fn change(now: Duration, before: Duration) -> String {
+    if before.is_zero() {
+        return "-".to_string();
+    }
+    format!(
+        "{:+.1}%",
+        (now.as_secs_f64() / before.as_secs_f64() - 1.) * 100.
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn baseline_round_trip() {
+        let timing = |parse, solve, runs| Timing {
+            parse: Duration::from_nanos(parse),
+            solve: Duration::from_nanos(solve),
+            runs,
+        };
+        let results = [
+            ("06a".to_string(), timing(1_250, 80, 100)),
+            ("17b".to_string(), timing(4_000_000, 912_345_678, 2)),
+        ];
+        let text = format(&results);
+        assert_eq!(text.lines().nth(2), Some("17 b 4000000 912345678 2"));
+        let baseline = parse_baseline(&text);
+        assert_eq!(baseline.len(), 2);
+        for (name, t) in &results {
+            assert_eq!(baseline[name], (t.parse, t.solve), "{}", name);
+        }
+        // malformed and short lines are skipped
+        let baseline = parse_baseline("17 b x 1 1\n17\n\n# 06 a 1 1 1\n06 a 5 6\n");
+        assert_eq!(baseline.len(), 1);
+        assert_eq!(
+            baseline["06a"],
+            (Duration::from_nanos(5), Duration::from_nanos(6))
+        );
+    }
+}
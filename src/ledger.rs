@@ -0,0 +1,116 @@
+use std::collections::BTreeMap;
+use std::io::{self, ErrorKind};
+use std::path::PathBuf;
+use std::{env, fs};
+
+// file of confirmed answers; inputs are per account, so it stays local
+const LEDGER_ENV: &str = "AOC_LEDGER";
+const LEDGER_DEFAULT: &str = "answers.txt";
+
+// FNV-1a over the input, so the key does not change between builds or toolchains
+pub fn hash(input: &str) -> u64 {
+    input.trim_end().bytes().fold(0xcbf29ce484222325, |h, b| {
+        (h ^ b as u64).wrapping_mul(0x100000001b3)
+    })
+}
+
+pub enum Status {
+    Unknown,
+    Confirmed,
+    Regression(String),
+}
+
+// confirmed answer per part name (e.g. "17b") and input hash,
+// stored as one "PART HASH ANSWER" line each; other lines are dropped
+pub struct Ledger {
+    path: PathBuf,
+    answers: BTreeMap<(String, u64), String>,
+}
+
+impl Ledger {
+    pub fn path() -> PathBuf {
+        env::var_os(LEDGER_ENV)
+            .unwrap_or_else(|| LEDGER_DEFAULT.into())
+            .into()
+    }
+
+    pub fn load() -> io::Result<Ledger> {
+        let path = Self::path();
+        let text = match fs::read_to_string(&path) {
+            Ok(text) => text,
+            Err(e) if e.kind() == ErrorKind::NotFound => String::new(),
+            Err(e) => return Err(e),
+        };
+        let answers = text
+            .lines()
+            .filter_map(|l| {
+                let mut fields = l.splitn(3, ' ');
+                let name = fields.next()?.to_string();
+                let hash = fields.next().filter(|h| h.len() == 16)?;
+                let hash = u64::from_str_radix(hash, 16).ok()?;
+                Some(((name, hash), fields.next()?.to_string()))
+            })
+            .collect();
+        Ok(Ledger { path, answers })
+    }
+
+    pub fn check(&self, name: &str, hash: u64, answer: &str) -> Status {
+        match self.answers.get(&(name.to_string(), hash)) {
+            None => Status::Unknown,
+            Some(confirmed) if confirmed == answer => Status::Confirmed,
+            Some(confirmed) => Status::Regression(confirmed.clone()),
+        }
+    }
+
+    pub fn confirm(&mut self, name: &str, hash: u64, answer: &str) {
+        self.answers
+            .insert((name.to_string(), hash), answer.to_string());
+    }
+
+    pub fn save(&self) -> io::Result<()> {
+        let text: String = self
+            .answers
+            .iter()
+            .map(|((name, hash), answer)| format!("{} {:016x} {}\n", name, hash, answer))
+            .collect();
+        fs::write(&self.path, text)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        let path = env::temp_dir().join(format!("aoc-ledger-{}.txt", std::process::id()));
+        fs::write(&path, "17b 00000000000004d2 94\nnot a line\n").unwrap();
+        env::set_var(LEDGER_ENV, &path);
+
+        let mut ledger = Ledger::load().unwrap();
+        assert!(matches!(ledger.check("17b", 1234, "94"), Status::Confirmed));
+        assert!(matches!(ledger.check("17b", 1234, "95"), Status::Regression(a) if a == "94"));
+        assert!(matches!(ledger.check("17a", 1234, "94"), Status::Unknown));
+        ledger.confirm("08b", hash("LR\n"), "never reached");
+        ledger.confirm("17b", 1234, "102");
+        ledger.save().unwrap();
+
+        let ledger = Ledger::load().unwrap();
+        assert!(matches!(
+            ledger.check("08b", hash("LR\n\n"), "never reached"),
+            Status::Confirmed
+        ));
+        assert!(matches!(
+            ledger.check("17b", 1234, "102"),
+            Status::Confirmed
+        ));
+        assert_eq!(
+            fs::read_to_string(&path).unwrap(),
+            format!(
+                "08b {:016x} never reached\n17b 00000000000004d2 102\n",
+                hash("LR")
+            )
+        );
+        fs::remove_file(&path).unwrap();
+    }
+}
@@ -1,5 +1,455 @@
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+use std::{env, fs, process};
+
+use aoc::{ParseError, Part, Report, Solution};
+
+mod batch;
+mod bench;
+mod ledger;
+
+use ledger::{Ledger, Status};
+
+// directory holding one dayNN.txt per day, shared by both parts
+const INPUTS_ENV: &str = "AOC_INPUTS";
+// where `advent bench` writes its results; keep a copy to compare later runs against
+const BENCH_OUTPUT: &str = "bench_output.txt";
+
+struct Day {
+    day: u8,
+    part: Part,
+    solve: fn(&str) -> Result<String, ParseError>,
+    bench: fn(&str) -> Result<bench::Timing, ParseError>,
+}
+
+impl Day {
+    fn new<S: Solution>() -> Self {
+        Day {
+            day: S::DAY,
+            part: S::PART,
+            solve: |input| aoc::solve::<S>(input).map(|a| a.to_string()),
+            bench: bench::measure::<S>,
+        }
+    }
+    fn number(&self) -> String {
+        format!("{:02}", self.day)
+    }
+    // e.g. "17b"
+    fn name(&self) -> String {
+        format!("{:02}{}", self.day, self.part)
+    }
+    // the warnings raised by the solve that just finished go with it
+    fn report(&self, answer: Result<String, String>, elapsed: Duration) -> Report {
+        Report {
+            day: self.day,
+            part: self.part,
+            answer,
+            elapsed,
+            warnings: aoc::take_warnings(),
+        }
+    }
+}
+
+macro_rules! days {
+    ($($solution:path),* $(,)?) => {
+        vec![$(Day::new::<$solution>()),*]
+    };
+}
+
+fn days() -> Vec<Day> {
+    days![
+        day02a::Day02a,
+        day02b::Day02b,
+        day03a::Day03a,
+        day03b::Day03b,
+        day04a::Day04a,
+        day04b::Day04b,
+        day05a::Day05a,
+        day05b::Day05b,
+        day06::Day06a,
+        day06::Day06b,
+        day07::Day07a,
+        day07::Day07b,
+        day08a::Day08a,
+        day08b::Day08b,
+        day09a::Day09a,
+        day09b::Day09b,
+        day10a::Day10a,
+        day10b::Day10b,
+        day11a::Day11a,
+        day11b::Day11b,
+        day12a::Day12a,
+        day12b::Day12b,
+        day13a::Day13a,
+        day13b::Day13b,
+        day14a::Day14a,
+        day14b::Day14b,
+        day15a::Day15a,
+        day15b::Day15b,
+        day16a::Day16a,
+        day16b::Day16b,
+        day17a::Day17a,
+        day17b::Day17b,
+        day18a::Day18a,
+        day18b::Day18b,
+        day19a::Day19a,
+        day19b::Day19b,
+        day20a::Day20a,
+        day20b::Day20b,
+        day21a::Day21a,
+        day21b::Day21b,
+        day22a::Day22a,
+        day22b::Day22b,
+        day23a::Day23a,
+        day23b::Day23b,
+        day25a::Day25a,
+    ]
+}
+
+// "all", a day such as "17" or "day17", or a single part such as "17b"
+fn select(days: Vec<Day>, which: &str) -> Vec<Day> {
+    let which = which.trim_start_matches("day");
+    let digits = which.bytes().take_while(u8::is_ascii_digit).count();
+    let which = match digits {
+        1 => format!("0{}", which),
+        _ => which.to_string(),
+    };
+    days.into_iter()
+        .filter(|d| which == "all" || d.name().starts_with(&which))
+        .collect()
+}
+
+fn input_path(day: &Day) -> PathBuf {
+    let dir = env::var_os(INPUTS_ENV).unwrap_or_else(|| "inputs".into());
+    PathBuf::from(dir).join(format!("day{}.txt", day.number()))
+}
+
+fn millis(d: Duration) -> String {
+    format!("{:.3}ms", d.as_secs_f64() * 1e3)
+}
+
+fn selected(which: &str) -> Vec<Day> {
+    let days = select(days(), which);
+    if days.is_empty() {
+        eprintln!("error: no solution matches {:?}", which);
+        process::exit(1);
+    }
+    days
+}
+
+fn load_ledger() -> Ledger {
+    Ledger::load().unwrap_or_else(|e| {
+        eprintln!(
+            "error: cannot read ledger {}: {}",
+            Ledger::path().display(),
+            e
+        );
+        process::exit(1)
+    })
+}
+
+fn run(which: &str, json: bool) {
+    let days = selected(which);
+    let ledger = load_ledger();
+    if !json {
+        println!(
+            "{:<4} {:<5} {:>20} {:>14}  ledger",
+            "day", "part", "answer", "time"
+        );
+    }
+    let mut total = Duration::ZERO;
+    let mut regressions = 0;
+    let mut failures = 0;
+    for day in days {
+        let path = input_path(&day);
+        let Ok(input) = fs::read_to_string(&path) else {
+            if json {
+                let missing = format!("no {}", path.display());
+                println!("{}", day.report(Err(missing), Duration::ZERO).to_json());
+                continue;
+            }
+            println!(
+                "{:<4} {:<5} {:>20} {:>14}",
+                day.number(),
+                day.part,
+                format!("no {}", path.display()),
+                "-"
+            );
+            continue;
+        };
+        aoc::take_warnings();
+        let start = Instant::now();
+        let answer = (day.solve)(&input).map_err(|e| e.to_string());
+        let elapsed = start.elapsed();
+        total += elapsed;
+        let mut report = day.report(answer, elapsed);
+        let (shown, status) = match &report.answer {
+            Ok(answer) => {
+                let status = match ledger.check(&day.name(), ledger::hash(&input), answer) {
+                    Status::Unknown => "new".to_string(),
+                    Status::Confirmed => "ok".to_string(),
+                    Status::Regression(confirmed) => {
+                        regressions += 1;
+                        report
+                            .warnings
+                            .push(format!("answer differs from the confirmed {}", confirmed));
+                        format!("REGRESSION, confirmed {}", confirmed)
+                    }
+                };
+                (answer.clone(), status)
+            }
+            Err(e) => {
+                failures += 1;
+                ("parse error".to_string(), e.clone())
+            }
+        };
+        if json {
+            println!("{}", report.to_json());
+            continue;
+        }
+        println!(
+            "{:<4} {:<5} {:>20} {:>14}  {}",
+            day.number(),
+            day.part,
+            shown,
+            millis(elapsed),
+            status
+        );
+        for w in &report.warnings {
+            eprintln!("warning: {}: {}", day.name(), w);
+        }
+    }
+    if !json {
+        println!("{:<4} {:<5} {:>20} {:>14}", "", "", "total", millis(total));
+    }
+    if failures > 0 {
+        eprintln!("error: {} input(s) could not be parsed", failures);
+    }
+    if regressions > 0 {
+        eprintln!("error: {} answer(s) differ from the ledger", regressions);
+    }
+    if failures + regressions > 0 {
+        process::exit(1);
+    }
+}
+
+// record the current answers, or a given one for a single part, as correct for these inputs
+fn confirm(which: &str, answer: Option<&str>) {
+    let days = selected(which);
+    if answer.is_some() && days.len() != 1 {
+        eprintln!("error: an explicit answer needs a single part such as 17b");
+        process::exit(1);
+    }
+    let mut ledger = load_ledger();
+    for day in days {
+        let path = input_path(&day);
+        let Ok(input) = fs::read_to_string(&path) else {
+            eprintln!("skipping {}: no {}", day.name(), path.display());
+            continue;
+        };
+        let answer = match answer {
+            Some(answer) => answer.to_string(),
+            None => match (day.solve)(&input) {
+                Ok(answer) => answer,
+                Err(e) => {
+                    eprintln!("skipping {}: {}", day.name(), e);
+                    continue;
+                }
+            },
+        };
+        println!(
+            "{:<4} {:<5} {:>20}  confirmed",
+            day.number(),
+            day.part,
+            answer
+        );
+        ledger.confirm(&day.name(), ledger::hash(&input), &answer);
+    }
+    if let Err(e) = ledger.save() {
+        eprintln!(
+            "error: cannot write ledger {}: {}",
+            Ledger::path().display(),
+            e
+        );
+        process::exit(1);
+    }
+}
+
+fn bench(which: &str, baseline: Option<&str>) {
+    let days = selected(which);
+    let baseline = baseline.map(|path| {
+        let text = fs::read_to_string(path).unwrap_or_else(|e| {
+            eprintln!("error: cannot read baseline {}: {}", path, e);
+            process::exit(1)
+        });
+        bench::parse_baseline(&text)
+    });
+    print!(
+        "{:<4} {:<5} {:>14} {:>14} {:>5}",
+        "day", "part", "parse", "solve", "runs"
+    );
+    if baseline.is_some() {
+        print!(" {:>9} {:>9}", "vs parse", "vs solve");
+    }
+    println!();
+    let mut results = Vec::new();
+    for day in days {
+        let path = input_path(&day);
+        let Ok(input) = fs::read_to_string(&path) else {
+            println!(
+                "{:<4} {:<5} {:>14}",
+                day.number(),
+                day.part,
+                format!("no {}", path.display())
+            );
+            continue;
+        };
+        let t = match (day.bench)(&input) {
+            Ok(t) => t,
+            Err(e) => {
+                println!(
+                    "{:<4} {:<5} {:>14}  {}",
+                    day.number(),
+                    day.part,
+                    "parse error",
+                    e
+                );
+                continue;
+            }
+        };
+        print!(
+            "{:<4} {:<5} {:>14} {:>14} {:>5}",
+            day.number(),
+            day.part,
+            millis(t.parse),
+            millis(t.solve),
+            t.runs
+        );
+        if let Some(baseline) = &baseline {
+            match baseline.get(&day.name()) {
+                Some(&(parse, solve)) => print!(
+                    " {:>9} {:>9}",
+                    bench::change(t.parse, parse),
+                    bench::change(t.solve, solve)
+                ),
+                None => print!(" {:>9} {:>9}", "new", "new"),
+            }
+        }
+        println!();
+        results.push((day.name(), t));
+    }
+    if let Err(e) = fs::write(BENCH_OUTPUT, bench::format(&results)) {
+        eprintln!("error: cannot write {}: {}", BENCH_OUTPUT, e);
+        process::exit(1);
+    }
+}
+
+// every selected part on every file in dir, e.g. inputs collected from several accounts
+fn batch(which: &str, dir: &str) {
+    let days = selected(which);
+    let inputs = batch::inputs(Path::new(dir)).unwrap_or_else(|e| {
+        eprintln!("error: cannot list {}: {}", dir, e);
+        process::exit(1)
+    });
+    if inputs.is_empty() {
+        eprintln!("error: no input files in {}", dir);
+        process::exit(1);
+    }
+    batch::quiet_panics();
+    let width = inputs
+        .iter()
+        .map(|p| p.file_name().unwrap_or_default().len())
+        .max()
+        .unwrap_or(0)
+        .max("input".len());
+    println!(
+        "{:<width$} {:<4} {:<5} {:>20} {:>14}",
+        "input", "day", "part", "answer", "time"
+    );
+    let mut failures = 0;
+    for path in &inputs {
+        let name = path.file_name().unwrap_or_default().to_string_lossy();
+        let input = match fs::read_to_string(path) {
+            Ok(input) => input,
+            Err(e) => {
+                failures += days.len();
+                println!(
+                    "{:<width$} {:<4} {:<5} {:>20}  {}",
+                    name, "-", "-", "FAILED", e
+                );
+                continue;
+            }
+        };
+        for day in &days {
+            let (result, elapsed) = batch::attempt(day.solve, &input);
+            match result {
+                Ok(answer) => println!(
+                    "{:<width$} {:<4} {:<5} {:>20} {:>14}",
+                    name,
+                    day.number(),
+                    day.part,
+                    answer,
+                    millis(elapsed)
+                ),
+                Err(why) => {
+                    failures += 1;
+                    println!(
+                        "{:<width$} {:<4} {:<5} {:>20} {:>14}  {}",
+                        name,
+                        day.number(),
+                        day.part,
+                        "FAILED",
+                        millis(elapsed),
+                        why
+                    );
+                }
+            }
+            for w in aoc::take_warnings() {
+                eprintln!("warning: {} {}: {}", name, day.name(), w);
+            }
+        }
+    }
+    if failures > 0 {
+        eprintln!(
+            "error: {} of {} run(s) failed",
+            failures,
+            inputs.len() * days.len()
+        );
+        process::exit(1);
+    }
+}
+
+fn usage() -> ! {
+    eprintln!("usage: advent run [--json] <all | DAY | DAYPART>   e.g. advent run 17b");
+    eprintln!(
+        "       advent bench <all | DAY | DAYPART> [BASELINE]   writes {}",
+        BENCH_OUTPUT
+    );
+    eprintln!("       advent batch <all | DAY | DAYPART> DIR   runs every file in DIR");
+    eprintln!(
+        "       advent confirm <all | DAY | DAYPART> [ANSWER]   records answers in {}",
+        Ledger::path().display()
+    );
+    eprintln!(
+        "inputs are read from ${}/dayNN.txt (default inputs/dayNN.txt)",
+        INPUTS_ENV
+    );
+    process::exit(2)
+}
+
 fn main() {
-    println!("run with");
-    println!("$ cargo run -p day02b");
-    assert!(include_str!("/tmp/input.txt").len() > 0);
+    let args: Vec<String> = env::args().skip(1).collect();
+    match args.iter().map(String::as_str).collect::<Vec<_>>()[..] {
+        ["run", "--json", which] => run(which, true),
+        ["run", "--json"] => run("all", true),
+        ["run", which] => run(which, false),
+        ["run"] => run("all", false),
+        ["bench", which, baseline] => bench(which, Some(baseline)),
+        ["bench", which] => bench(which, None),
+        ["bench"] => bench("all", None),
+        ["batch", which, dir] => batch(which, dir),
+        ["confirm", which, answer] => confirm(which, Some(answer)),
+        ["confirm", which] => confirm(which, None),
+        _ => usage(),
+    }
 }